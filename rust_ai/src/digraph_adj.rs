@@ -0,0 +1,138 @@
+//! Directed Graph Adjacency Trait
+//!
+//! This module defines a small adjacency trait so graph algorithms can
+//! accept any container shape rather than one concrete representation,
+//! mirroring how petgraph lets algorithms operate over graph traits instead
+//! of a single struct. [`NegCycleFinderQ`](crate::neg_cycle_q::NegCycleFinderQ)
+//! is generic over anything implementing this trait, including the nested
+//! `HashMap<N, HashMap<N, E>>` representation and `TinyDiGraph`, so a
+//! `TinyDiGraph` can be analyzed directly, without copying it into a nested
+//! map first. Accessors return borrowing iterators rather than owned `Vec`s
+//! so that using a representation directly (instead of through a separate
+//! copy) doesn't itself force an allocation on every call.
+
+use std::collections::HashMap;
+
+use crate::tiny_digraph::TinyDiGraph;
+use crate::types::{Edge, Node};
+
+/// Adjacency access for directed graphs, independent of the underlying
+/// container.
+pub trait DiGraphAdj<N, E>
+where
+    N: Node,
+    E: Edge,
+{
+    /// All nodes in the graph.
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    where
+        N: 'a;
+
+    /// The out-edges of `u`, as `(target, edge)` pairs.
+    fn out_edges<'a>(&'a self, u: &N) -> impl Iterator<Item = (&'a N, &'a E)>
+    where
+        N: 'a,
+        E: 'a;
+}
+
+impl<N, E> DiGraphAdj<N, E> for HashMap<N, HashMap<N, E>>
+where
+    N: Node,
+    E: Edge,
+{
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    where
+        N: 'a,
+    {
+        self.keys()
+    }
+
+    fn out_edges<'a>(&'a self, u: &N) -> impl Iterator<Item = (&'a N, &'a E)>
+    where
+        N: 'a,
+        E: 'a,
+    {
+        self.get(u).into_iter().flat_map(|neighbors| neighbors.iter())
+    }
+}
+
+impl<N, E> DiGraphAdj<N, E> for TinyDiGraph<N, E>
+where
+    N: Node,
+    E: Edge,
+{
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    where
+        N: 'a,
+    {
+        TinyDiGraph::nodes(self)
+    }
+
+    fn out_edges<'a>(&'a self, u: &N) -> impl Iterator<Item = (&'a N, &'a E)>
+    where
+        N: 'a,
+        E: 'a,
+    {
+        self.neighbors(u)
+    }
+}
+
+/// Lets a finder be built from a borrowed graph (e.g. `from_adj(&tiny_digraph)`)
+/// without the borrow forcing its own copy.
+impl<N, E, T> DiGraphAdj<N, E> for &T
+where
+    N: Node,
+    E: Edge,
+    T: DiGraphAdj<N, E>,
+{
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    where
+        N: 'a,
+    {
+        (**self).nodes()
+    }
+
+    fn out_edges<'a>(&'a self, u: &N) -> impl Iterator<Item = (&'a N, &'a E)>
+    where
+        N: 'a,
+        E: 'a,
+    {
+        (**self).out_edges(u)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hashmap_adapter() {
+        let mut digraph: HashMap<&str, HashMap<&str, i32>> = HashMap::new();
+        digraph.insert("a", HashMap::from([("b", 1)]));
+        digraph.insert("b", HashMap::new());
+
+        let mut nodes: Vec<&&str> = DiGraphAdj::nodes(&digraph).collect();
+        nodes.sort();
+        assert_eq!(nodes, vec![&"a", &"b"]);
+        assert_eq!(
+            DiGraphAdj::out_edges(&digraph, &"a").collect::<Vec<_>>(),
+            vec![(&"b", &1)]
+        );
+        assert_eq!(DiGraphAdj::out_edges(&digraph, &"b").count(), 0);
+    }
+
+    #[test]
+    fn test_tiny_digraph_adapter() {
+        let mut gr: TinyDiGraph<i32, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1]);
+        gr.add_edge(&0, &1, 2.5);
+
+        let mut nodes: Vec<&i32> = DiGraphAdj::nodes(&gr).collect();
+        nodes.sort();
+        assert_eq!(nodes, vec![&0, &1]);
+        assert_eq!(
+            DiGraphAdj::out_edges(&gr, &0).collect::<Vec<_>>(),
+            vec![(&1, &2.5)]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Floyd-Warshall All-Pairs Shortest Paths
+//!
+//! This module adds a dense all-pairs shortest path routine over the same
+//! `HashMap<N, HashMap<N, E>>` representation used by `NegCycleFinder`. It
+//! complements the Howard/Bellman-Ford path (which only reports a cycle) by
+//! also returning the full distance table, at the cost of the usual
+//! `O(n^3)` triple loop. Negative cycles are detected by the textbook
+//! `dist[v][v] < 0` test after the triple loop completes, and the
+//! certifying cycle is reconstructed by walking the predecessor matrix from
+//! `v` back to `v`.
+
+use std::collections::HashMap;
+
+use crate::neg_cycle::NegativeCycle;
+use crate::types::{Cycle, Domain, Edge, Node};
+
+/// Computes all-pairs shortest path distances via the Floyd-Warshall
+/// algorithm.
+///
+/// # Arguments
+///
+/// * `digraph` - The graph structure where nodes map to neighbors and edge attributes
+/// * `get_weight` - Function to get the weight of an edge
+///
+/// # Returns
+///
+/// A map from `(source, target)` to shortest distance for every reachable
+/// pair, or the first negative cycle found if the distances are undefined.
+pub fn floyd_warshall_all_pairs<N, E, D>(
+    digraph: &HashMap<N, HashMap<N, E>>,
+    get_weight: impl Fn(&E) -> D,
+) -> Result<HashMap<(N, N), D>, NegativeCycle<E>>
+where
+    N: Node,
+    E: Edge,
+    D: Domain,
+{
+    let nodes: Vec<N> = digraph.keys().cloned().collect();
+    let index: HashMap<N, usize> = nodes.iter().cloned().enumerate().map(|(i, n)| (n, i)).collect();
+    let n = nodes.len();
+
+    let mut dist: Vec<Vec<Option<D>>> = vec![vec![None; n]; n];
+    let mut pred: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+
+    for (i, _) in nodes.iter().enumerate() {
+        dist[i][i] = Some(D::zero());
+        pred[i][i] = Some(i);
+    }
+
+    for (u, neighbors) in digraph {
+        let i = index[u];
+        for (v, edge) in neighbors {
+            let j = index[v];
+            let w = get_weight(edge);
+            let better = match &dist[i][j] {
+                Some(existing) => w < *existing,
+                None => true,
+            };
+            if better {
+                dist[i][j] = Some(w);
+                pred[i][j] = Some(i);
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(d_ik) = dist[i][k].clone() else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(d_kj) = dist[k][j].clone() else {
+                    continue;
+                };
+                let candidate = d_ik.clone() + d_kj;
+                let better = match &dist[i][j] {
+                    Some(existing) => candidate < *existing,
+                    None => true,
+                };
+                if better {
+                    dist[i][j] = Some(candidate);
+                    pred[i][j] = pred[k][j];
+                }
+            }
+        }
+    }
+
+    for (v, d_vv) in dist.iter().enumerate().map(|(v, row)| (v, &row[v])) {
+        let Some(d_vv) = d_vv else {
+            continue;
+        };
+        if *d_vv < D::zero() {
+            let mut walk = vec![v];
+            let mut cur = v;
+            loop {
+                cur = pred[v][cur].expect("negative dist[v][v] implies a predecessor chain back to v");
+                walk.push(cur);
+                if cur == v {
+                    break;
+                }
+            }
+            walk.reverse();
+
+            let cycle: Cycle<E> = walk
+                .windows(2)
+                .map(|pair| digraph[&nodes[pair[0]]][&nodes[pair[1]]].clone())
+                .collect();
+            return Err(NegativeCycle { cycle });
+        }
+    }
+
+    let mut distances: HashMap<(N, N), D> = HashMap::new();
+    for i in 0..n {
+        for j in 0..n {
+            if let Some(d) = &dist[i][j] {
+                distances.insert((nodes[i].clone(), nodes[j].clone()), d.clone());
+            }
+        }
+    }
+
+    Ok(distances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floyd_warshall_with_negative_edge() {
+        let mut digraph: HashMap<i32, HashMap<i32, f64>> = HashMap::new();
+        digraph.insert(0, HashMap::from([(1, 4.0), (2, 5.0)]));
+        digraph.insert(1, HashMap::from([(2, -2.0)]));
+        digraph.insert(2, HashMap::new());
+
+        let distances = floyd_warshall_all_pairs(&digraph, |w| *w).expect("no negative cycle");
+        assert_eq!(distances[&(0, 2)], 2.0);
+        assert_eq!(distances[&(0, 1)], 4.0);
+        assert_eq!(distances[&(0, 0)], 0.0);
+    }
+
+    #[test]
+    fn test_floyd_warshall_detects_negative_cycle() {
+        let mut digraph: HashMap<&str, HashMap<&str, f64>> = HashMap::new();
+        digraph.insert("a", HashMap::from([("b", 1.0)]));
+        digraph.insert("b", HashMap::from([("c", 1.0)]));
+        digraph.insert("c", HashMap::from([("a", -5.0)]));
+
+        let result = floyd_warshall_all_pairs(&digraph, |w| *w);
+        let err = result.expect_err("a negative cycle should be detected");
+        assert_eq!(err.cycle.len(), 3);
+    }
+
+    #[test]
+    fn test_floyd_warshall_unreachable_pair_is_absent() {
+        let mut digraph: HashMap<i32, HashMap<i32, f64>> = HashMap::new();
+        digraph.insert(0, HashMap::from([(1, 1.0)]));
+        digraph.insert(1, HashMap::new());
+        digraph.insert(2, HashMap::new());
+
+        let distances = floyd_warshall_all_pairs(&digraph, |w| *w).expect("no negative cycle");
+        assert!(!distances.contains_key(&(0, 2)));
+        assert!(!distances.contains_key(&(2, 0)));
+    }
+}
@@ -0,0 +1,189 @@
+//! Johnson's All-Pairs Shortest Paths
+//!
+//! This module adds an all-pairs shortest path routine that tolerates
+//! negative edge weights by reusing `NegCycleFinder`'s Bellman-Ford
+//! relaxation for reweighting. It computes potentials `h(v) = dist(q, v)`
+//! for a virtual zero-weight source `q` (modeled by seeding every node's
+//! distance at zero, which is equivalent to adding `q` with a zero-weight
+//! edge to every node), reports a certifying negative cycle if one is
+//! found, otherwise reweights each edge `(u, v)` to
+//! `w'(u, v) = w(u, v) + h(u) - h(v) >= 0` and runs Dijkstra from every
+//! node, finally correcting each distance back via
+//! `d(u, v) = d'(u, v) - h(u) + h(v)`.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use crate::neg_cycle::{NegCycleFinder, NegativeCycle};
+use crate::tiny_digraph::TinyDiGraph;
+use crate::types::Domain;
+
+/// Min-heap wrapper ordering solely by `cost`, since `Domain` only requires
+/// `PartialOrd`.
+struct HeapItem<N, W> {
+    cost: W,
+    node: N,
+}
+
+impl<N, W: PartialOrd> PartialEq for HeapItem<N, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.partial_cmp(&other.cost) == Some(Ordering::Equal)
+    }
+}
+
+impl<N, W: PartialOrd> Eq for HeapItem<N, W> {}
+
+impl<N, W: PartialOrd> PartialOrd for HeapItem<N, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, W: PartialOrd> Ord for HeapItem<N, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap` (a max-heap) pops the smallest cost first.
+        other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Plain Dijkstra from `start` over non-negative edge weights, returning the
+/// distance to every reachable node (including `start` itself, at zero).
+fn dijkstra_all<N, E, W>(
+    graph: &TinyDiGraph<N, E>,
+    start: &N,
+    weight: &impl Fn(&N, &N, &E) -> W,
+) -> HashMap<N, W>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+    W: Domain,
+{
+    let mut dist: HashMap<N, W> = HashMap::new();
+    let mut visited: std::collections::HashSet<N> = std::collections::HashSet::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start.clone(), W::zero());
+    heap.push(HeapItem {
+        cost: W::zero(),
+        node: start.clone(),
+    });
+
+    while let Some(HeapItem { cost, node }) = heap.pop() {
+        if visited.contains(&node) {
+            continue;
+        }
+        visited.insert(node.clone());
+
+        for (next, edge) in graph.neighbors(&node) {
+            let new_cost = cost.clone() + weight(&node, next, edge);
+            let better = match dist.get(next) {
+                Some(existing) => new_cost < *existing,
+                None => true,
+            };
+            if better {
+                dist.insert(next.clone(), new_cost.clone());
+                heap.push(HeapItem {
+                    cost: new_cost,
+                    node: next.clone(),
+                });
+            }
+        }
+    }
+
+    dist
+}
+
+impl<N, E> TinyDiGraph<N, E>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+{
+    /// Computes all-pairs shortest path distances, tolerating negative edge
+    /// weights as long as no negative cycle is reachable.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - Weight function `(u, v, edge) -> weight`
+    ///
+    /// # Returns
+    ///
+    /// A map from `(source, target)` to shortest distance, or the first
+    /// negative cycle found if the distances are undefined.
+    pub fn johnson_all_pairs<W>(
+        &self,
+        weight: impl Fn(&N, &N, &E) -> W,
+    ) -> Result<HashMap<(N, N), W>, NegativeCycle<E>>
+    where
+        W: Domain,
+    {
+        // NegCycleFinder requires a hashable edge type, which the caller's E
+        // need not be; index edges instead and keep the original edge plus
+        // its weight in a side table.
+        let mut side_table: Vec<(N, N, E, W)> = Vec::new();
+        let mut digraph: HashMap<N, HashMap<N, usize>> = HashMap::new();
+        for node in self.nodes() {
+            digraph.entry(node.clone()).or_default();
+        }
+        for (u, v, e) in self.edges() {
+            let w = weight(u, v, e);
+            let idx = side_table.len();
+            side_table.push((u.clone(), v.clone(), e.clone(), w));
+            digraph.entry(u.clone()).or_default().insert(v.clone(), idx);
+        }
+
+        // Potentials h(v) = dist(q, v) for a virtual zero-weight source q.
+        let mut h: HashMap<N, W> = self.nodes().map(|n| (n.clone(), W::zero())).collect();
+        let mut ncf: NegCycleFinder<N, usize, W> = NegCycleFinder::new(digraph);
+        let cycles = ncf.howard(&mut h, |&idx| side_table[idx].3.clone());
+
+        if let Some(cycle) = cycles.into_iter().next() {
+            return Err(NegativeCycle {
+                cycle: cycle.into_iter().map(|idx| side_table[idx].2.clone()).collect(),
+            });
+        }
+
+        let mut distances: HashMap<(N, N), W> = HashMap::new();
+        for source in self.nodes() {
+            let reweighted =
+                |u: &N, v: &N, e: &E| weight(u, v, e) + h[u].clone() - h[v].clone();
+            let dprime = dijkstra_all(self, source, &reweighted);
+            for (target, dp) in dprime {
+                let corrected = dp - h[source].clone() + h[&target].clone();
+                distances.insert((source.clone(), target), corrected);
+            }
+        }
+
+        Ok(distances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_johnson_with_negative_edge() {
+        let mut gr: TinyDiGraph<i32, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2]);
+        gr.add_edge(&0, &1, 4.0);
+        gr.add_edge(&0, &2, 5.0);
+        gr.add_edge(&1, &2, -2.0);
+
+        let distances = gr.johnson_all_pairs(|_u, _v, w| *w).expect("no negative cycle");
+        assert_eq!(distances[&(0, 2)], 2.0);
+        assert_eq!(distances[&(0, 1)], 4.0);
+    }
+
+    #[test]
+    fn test_johnson_detects_negative_cycle() {
+        let mut gr: TinyDiGraph<&str, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec!["a", "b", "c"]);
+        gr.add_edge(&"a", &"b", 1.0);
+        gr.add_edge(&"b", &"c", 1.0);
+        gr.add_edge(&"c", &"a", -5.0);
+
+        let result = gr.johnson_all_pairs(|_u, _v, w| *w);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,281 @@
+//! K-Shortest Simple Paths
+//!
+//! This module adds Yen's algorithm to `TinyDiGraph`, ranking the `k`
+//! shortest loopless paths between two nodes under a pluggable weight
+//! function. The first path `A_0` comes from plain Dijkstra; each following
+//! path is found by, for every "spur node" along the previous path, taking
+//! its root prefix, temporarily removing the edges (and root-path nodes)
+//! already used by paths sharing that prefix, running Dijkstra from the spur
+//! node to the target, and pushing the resulting root+spur candidate into a
+//! min-heap keyed by total weight. The cheapest unused candidate becomes the
+//! next path.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::tiny_digraph::TinyDiGraph;
+use crate::types::Domain;
+
+/// Min-heap wrapper ordering solely by `cost`, since `Domain` only requires
+/// `PartialOrd`.
+struct HeapItem<T, W> {
+    cost: W,
+    item: T,
+}
+
+impl<T, W: PartialOrd> PartialEq for HeapItem<T, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.partial_cmp(&other.cost) == Some(Ordering::Equal)
+    }
+}
+
+impl<T, W: PartialOrd> Eq for HeapItem<T, W> {}
+
+impl<T, W: PartialOrd> PartialOrd for HeapItem<T, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, W: PartialOrd> Ord for HeapItem<T, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap` (a max-heap) pops the smallest cost first.
+        other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Dijkstra's algorithm restricted to a simple path: `removed_nodes` and
+/// `removed_edges` are skipped entirely, which is how Yen's algorithm keeps
+/// each candidate path loopless and distinct from paths found so far.
+fn shortest_path<N, E, W>(
+    graph: &TinyDiGraph<N, E>,
+    start: &N,
+    target: &N,
+    removed_nodes: &HashSet<N>,
+    removed_edges: &HashSet<(N, N)>,
+    weight: &impl Fn(&N, &N, &E) -> W,
+) -> Option<(W, Vec<N>)>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+    W: Domain,
+{
+    if removed_nodes.contains(start) || removed_nodes.contains(target) {
+        return None;
+    }
+
+    let mut dist: HashMap<N, W> = HashMap::new();
+    let mut pred: HashMap<N, N> = HashMap::new();
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start.clone(), W::zero());
+    heap.push(HeapItem {
+        cost: W::zero(),
+        item: start.clone(),
+    });
+
+    while let Some(HeapItem { cost, item: node }) = heap.pop() {
+        if visited.contains(&node) {
+            continue;
+        }
+        visited.insert(node.clone());
+
+        if &node == target {
+            break;
+        }
+
+        for (next, edge) in graph.neighbors(&node) {
+            if removed_nodes.contains(next) || removed_edges.contains(&(node.clone(), next.clone()))
+            {
+                continue;
+            }
+
+            let new_cost = cost.clone() + weight(&node, next, edge);
+            let better = match dist.get(next) {
+                Some(existing) => new_cost < *existing,
+                None => true,
+            };
+
+            if better {
+                dist.insert(next.clone(), new_cost.clone());
+                pred.insert(next.clone(), node.clone());
+                heap.push(HeapItem {
+                    cost: new_cost,
+                    item: next.clone(),
+                });
+            }
+        }
+    }
+
+    let total = dist.get(target)?.clone();
+    let mut path = vec![target.clone()];
+    let mut cur = target.clone();
+    while &cur != start {
+        let parent = pred.get(&cur)?;
+        path.push(parent.clone());
+        cur = parent.clone();
+    }
+    path.reverse();
+
+    Some((total, path))
+}
+
+/// Sums `weight` along the consecutive edges of `path`.
+fn path_cost<N, E, W>(
+    graph: &TinyDiGraph<N, E>,
+    path: &[N],
+    weight: &impl Fn(&N, &N, &E) -> W,
+) -> W
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+    W: Domain,
+{
+    let mut total = W::zero();
+    for window in path.windows(2) {
+        let (u, v) = (&window[0], &window[1]);
+        let (_, edge) = graph
+            .neighbors(u)
+            .find(|(n, _)| *n == v)
+            .expect("edge exists along a path returned by shortest_path");
+        total = total + weight(u, v, edge);
+    }
+    total
+}
+
+impl<N, E> TinyDiGraph<N, E>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+{
+    /// Computes the `k` shortest loopless paths from `source` to `target`
+    /// using Yen's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Starting node
+    /// * `target` - Destination node
+    /// * `k` - Maximum number of paths to return
+    /// * `weight` - Weight function `(u, v, edge) -> weight`
+    ///
+    /// # Returns
+    ///
+    /// Up to `k` `(total_weight, path)` pairs, ranked from cheapest to most
+    /// expensive. Fewer than `k` entries are returned if that many loopless
+    /// paths don't exist.
+    pub fn k_shortest_paths<W>(
+        &self,
+        source: &N,
+        target: &N,
+        k: usize,
+        weight: impl Fn(&N, &N, &E) -> W,
+    ) -> Vec<(W, Vec<N>)>
+    where
+        W: Domain,
+    {
+        let mut found: Vec<(W, Vec<N>)> = Vec::new();
+
+        let no_nodes: HashSet<N> = HashSet::new();
+        let no_edges: HashSet<(N, N)> = HashSet::new();
+        match shortest_path(self, source, target, &no_nodes, &no_edges, &weight) {
+            Some(first) => found.push(first),
+            None => return found,
+        }
+
+        let mut candidates: BinaryHeap<HeapItem<Vec<N>, W>> = BinaryHeap::new();
+        let mut seen_candidates: HashSet<Vec<N>> = HashSet::new();
+
+        while found.len() < k {
+            let prev_path = found.last().expect("at least one path was found").1.clone();
+
+            for spur_index in 0..prev_path.len().saturating_sub(1) {
+                let spur_node = &prev_path[spur_index];
+                let root_path = &prev_path[..=spur_index];
+
+                let mut removed_edges: HashSet<(N, N)> = HashSet::new();
+                for (_, path) in &found {
+                    if path.len() > spur_index + 1 && path[..=spur_index] == *root_path {
+                        removed_edges
+                            .insert((path[spur_index].clone(), path[spur_index + 1].clone()));
+                    }
+                }
+
+                let removed_nodes: HashSet<N> = root_path[..spur_index].iter().cloned().collect();
+
+                if let Some((spur_cost, spur_path)) =
+                    shortest_path(self, spur_node, target, &removed_nodes, &removed_edges, &weight)
+                {
+                    let root_cost = path_cost(self, &root_path[..=spur_index], &weight);
+                    let mut total_path = root_path[..spur_index].to_vec();
+                    total_path.extend(spur_path);
+                    let total_cost = root_cost + spur_cost;
+
+                    if seen_candidates.insert(total_path.clone()) {
+                        candidates.push(HeapItem {
+                            cost: total_cost,
+                            item: total_path,
+                        });
+                    }
+                }
+            }
+
+            match candidates.pop() {
+                Some(HeapItem { cost, item: path }) => found.push((cost, path)),
+                None => break,
+            }
+        }
+
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> TinyDiGraph<i32, f64> {
+        let mut gr: TinyDiGraph<i32, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2, 3, 4]);
+        gr.add_edge(&0, &1, 1.0);
+        gr.add_edge(&0, &2, 2.0);
+        gr.add_edge(&1, &3, 2.0);
+        gr.add_edge(&2, &3, 1.0);
+        gr.add_edge(&1, &2, 1.0);
+        gr.add_edge(&3, &4, 1.0);
+        gr
+    }
+
+    #[test]
+    fn test_first_path_is_shortest() {
+        let gr = sample_graph();
+        let paths = gr.k_shortest_paths(&0, &4, 1, |_u, _v, w| *w);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].0, 4.0);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_are_ranked_and_simple() {
+        let gr = sample_graph();
+        let paths = gr.k_shortest_paths(&0, &4, 3, |_u, _v, w| *w);
+
+        assert!(!paths.is_empty());
+        for window in paths.windows(2) {
+            assert!(window[0].0 <= window[1].0);
+        }
+        for (_, path) in &paths {
+            let mut nodes: Vec<_> = path.clone();
+            nodes.sort();
+            nodes.dedup();
+            assert_eq!(nodes.len(), path.len(), "path must be loopless");
+        }
+    }
+
+    #[test]
+    fn test_more_paths_requested_than_exist() {
+        let gr = sample_graph();
+        let paths = gr.k_shortest_paths(&0, &4, 100, |_u, _v, w| *w);
+        assert!(paths.len() < 100);
+    }
+}
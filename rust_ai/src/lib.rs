@@ -6,19 +6,33 @@
 //! - Parametric optimization algorithms
 //! - Efficient graph data structures
 
+mod scc;
 pub mod tiny_digraph;
 pub mod neg_cycle;
 pub mod neg_cycle_q;
 pub mod min_cycle_ratio;
 pub mod min_parametric_q;
 pub mod parametric;
+pub mod cycle_basis;
+pub mod max_flow;
+pub mod k_shortest_paths;
+pub mod johnson;
+pub mod min_mean_cycle;
+pub mod floyd_warshall;
+pub mod min_cost_flow;
+pub mod digraph_adj;
 
 pub use tiny_digraph::TinyDiGraph;
-pub use neg_cycle::NegCycleFinder;
+pub use neg_cycle::{NegCycleFinder, NegativeCycle};
 pub use neg_cycle_q::NegCycleFinderQ;
 pub use min_cycle_ratio::{MinCycleRatioSolver, CycleRatioAPI};
 pub use min_parametric_q::{MinParametricQSolver, MinParametricAPI};
 pub use parametric::{MaxParametricSolver, ParametricAPI};
+pub use cycle_basis::minimum_cycle_basis;
+pub use min_mean_cycle::MinMeanCycleSolver;
+pub use floyd_warshall::floyd_warshall_all_pairs;
+pub use min_cost_flow::MinCostFlowSolver;
+pub use digraph_adj::DiGraphAdj;
 
 /// Common types used throughout the library
 pub mod types {
@@ -29,9 +43,12 @@ pub mod types {
     pub trait Node: Hash + Eq + Clone {}
     impl<T: Hash + Eq + Clone> Node for T {}
 
-    /// Edge type - must be hashable and comparable
-    pub trait Edge: Hash + Eq + Clone {}
-    impl<T: Hash + Eq + Clone> Edge for T {}
+    /// Edge type - only needs to be cloneable. Edges are stored as
+    /// `HashMap` values (never keys) and are never hashed or compared for
+    /// equality anywhere in this crate, so floating-point weights and
+    /// tuples of them (e.g. `(cost, time)`) work as edge payloads.
+    pub trait Edge: Clone {}
+    impl<T: Clone> Edge for T {}
 
     /// Domain type for weights - supports arithmetic and comparison
     pub trait Domain: Clone + PartialOrd + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self> + std::ops::Mul<Output = Self> + std::ops::Div<Output = Self> + num::Zero + num::One {}
@@ -0,0 +1,309 @@
+//! Maximum Flow / Minimum Cut
+//!
+//! This module adds a max-flow / min-cut subsystem to `TinyDiGraph`, built on
+//! Dinic's algorithm: repeatedly build a level graph by BFS from the source
+//! over edges with positive residual capacity, then saturate it with a
+//! blocking flow found by DFS along admissible (level-increasing) edges,
+//! augmenting residuals on forward edges and their paired back-edges.
+//! Iteration stops once the sink is unreachable in the level graph, at which
+//! point the flow is maximum and the final residual reachability from the
+//! source yields a minimum cut.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::tiny_digraph::TinyDiGraph;
+use crate::types::Domain;
+
+/// One direction of a residual edge: the forward arc has `to` and a
+/// shrinking residual `cap`; its paired back-edge (`rev`) grows by the same
+/// amount whenever flow is pushed.
+struct FlowEdge<C> {
+    to: usize,
+    cap: C,
+    rev: usize,
+}
+
+/// Shared state produced by running Dinic's algorithm once, reused by both
+/// [`TinyDiGraph::max_flow`] and [`TinyDiGraph::min_cut`].
+struct DinicResult<N, C> {
+    nodes: Vec<N>,
+    adj: Vec<Vec<usize>>,
+    edges: Vec<FlowEdge<C>>,
+    /// `(source_index, target_index, forward_edge_index)` per original edge.
+    original_edges: Vec<(usize, usize, usize)>,
+    total_flow: C,
+}
+
+fn bfs_levels<C: Domain>(
+    n: usize,
+    src: usize,
+    adj: &[Vec<usize>],
+    edges: &[FlowEdge<C>],
+) -> Vec<Option<usize>> {
+    let mut level = vec![None; n];
+    level[src] = Some(0);
+    let mut queue = VecDeque::new();
+    queue.push_back(src);
+
+    while let Some(u) = queue.pop_front() {
+        for &e in &adj[u] {
+            let edge = &edges[e];
+            if edge.cap > C::zero() && level[edge.to].is_none() {
+                level[edge.to] = Some(level[u].expect("queued node has a level") + 1);
+                queue.push_back(edge.to);
+            }
+        }
+    }
+
+    level
+}
+
+/// Finds one augmenting path within the level graph and pushes its
+/// bottleneck capacity, returning the amount pushed (or `None` once `node`
+/// has no admissible edge left to explore).
+fn dfs_blocking<C: Domain>(
+    node: usize,
+    sink: usize,
+    level: &[Option<usize>],
+    iter: &mut [usize],
+    adj: &[Vec<usize>],
+    edges: &mut [FlowEdge<C>],
+) -> Option<C> {
+    while iter[node] < adj[node].len() {
+        let e = adj[node][iter[node]];
+        let to = edges[e].to;
+        let admissible = edges[e].cap > C::zero() && level[to] == level[node].map(|l| l + 1);
+
+        if admissible {
+            let bottleneck = if to == sink {
+                Some(edges[e].cap.clone())
+            } else {
+                dfs_blocking(to, sink, level, iter, adj, edges)
+                    .map(|b| if b < edges[e].cap { b } else { edges[e].cap.clone() })
+            };
+
+            if let Some(pushed) = bottleneck {
+                if pushed > C::zero() {
+                    edges[e].cap = edges[e].cap.clone() - pushed.clone();
+                    let rev = edges[e].rev;
+                    edges[rev].cap = edges[rev].cap.clone() + pushed.clone();
+                    return Some(pushed);
+                }
+            }
+        }
+
+        iter[node] += 1;
+    }
+
+    None
+}
+
+fn run_dinic<N, E, C>(
+    graph: &TinyDiGraph<N, E>,
+    source: &N,
+    sink: &N,
+    capacity: impl Fn(&N, &N, &E) -> C,
+) -> DinicResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+    C: Domain,
+{
+    let nodes: Vec<N> = graph.nodes().cloned().collect();
+    let node_index: HashMap<N, usize> = nodes
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, n)| (n, i))
+        .collect();
+    let n = nodes.len();
+    let src = node_index[source];
+    let snk = node_index[sink];
+
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut edges: Vec<FlowEdge<C>> = Vec::new();
+    let mut original_edges: Vec<(usize, usize, usize)> = Vec::new();
+
+    for (u, v, e) in graph.edges() {
+        let ui = node_index[u];
+        let vi = node_index[v];
+        let cap = capacity(u, v, e);
+
+        let fwd_idx = edges.len();
+        edges.push(FlowEdge {
+            to: vi,
+            cap,
+            rev: fwd_idx + 1,
+        });
+        let bwd_idx = edges.len();
+        edges.push(FlowEdge {
+            to: ui,
+            cap: C::zero(),
+            rev: fwd_idx,
+        });
+
+        adj[ui].push(fwd_idx);
+        adj[vi].push(bwd_idx);
+        original_edges.push((ui, vi, fwd_idx));
+    }
+
+    let mut total_flow = C::zero();
+
+    loop {
+        let level = bfs_levels(n, src, &adj, &edges);
+        if level[snk].is_none() {
+            break;
+        }
+
+        let mut iter = vec![0usize; n];
+        while let Some(pushed) = dfs_blocking(src, snk, &level, &mut iter, &adj, &mut edges) {
+            total_flow = total_flow + pushed;
+        }
+    }
+
+    DinicResult {
+        nodes,
+        adj,
+        edges,
+        original_edges,
+        total_flow,
+    }
+}
+
+impl<N, E> TinyDiGraph<N, E>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+{
+    /// Computes the maximum flow from `source` to `sink` via Dinic's
+    /// algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The flow's source node
+    /// * `sink` - The flow's sink node
+    /// * `capacity` - Non-negative capacity function `(u, v, edge) -> capacity`
+    ///
+    /// # Returns
+    ///
+    /// The maximum flow value and the flow carried on each original edge.
+    pub fn max_flow<C>(
+        &self,
+        source: &N,
+        sink: &N,
+        capacity: impl Fn(&N, &N, &E) -> C,
+    ) -> (C, HashMap<(N, N), C>)
+    where
+        C: Domain,
+    {
+        let result = run_dinic(self, source, sink, capacity);
+
+        let mut flow_map = HashMap::new();
+        for (ui, vi, fwd_idx) in &result.original_edges {
+            let rev = result.edges[*fwd_idx].rev;
+            let flow_value = result.edges[rev].cap.clone();
+            flow_map.insert(
+                (result.nodes[*ui].clone(), result.nodes[*vi].clone()),
+                flow_value,
+            );
+        }
+
+        (result.total_flow, flow_map)
+    }
+
+    /// Computes a minimum cut separating `source` from `sink`.
+    ///
+    /// Runs the same max-flow computation as [`TinyDiGraph::max_flow`], then
+    /// finds the nodes still reachable from `source` in the final residual
+    /// graph; the cut consists of the original edges leaving that set.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The flow's source node
+    /// * `sink` - The flow's sink node
+    /// * `capacity` - Non-negative capacity function `(u, v, edge) -> capacity`
+    ///
+    /// # Returns
+    ///
+    /// The cut's capacity (equal to the max-flow value) and its edges.
+    pub fn min_cut<C>(
+        &self,
+        source: &N,
+        sink: &N,
+        capacity: impl Fn(&N, &N, &E) -> C,
+    ) -> (C, Vec<(N, N)>)
+    where
+        C: Domain,
+    {
+        let result = run_dinic(self, source, sink, capacity);
+        let node_index: HashMap<N, usize> = result
+            .nodes
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+        let src = node_index[source];
+
+        let mut reachable = vec![false; result.nodes.len()];
+        reachable[src] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back(src);
+        while let Some(u) = queue.pop_front() {
+            for &e in &result.adj[u] {
+                let edge = &result.edges[e];
+                if edge.cap > C::zero() && !reachable[edge.to] {
+                    reachable[edge.to] = true;
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        let cut_edges: Vec<(N, N)> = result
+            .original_edges
+            .iter()
+            .filter(|(ui, vi, _)| reachable[*ui] && !reachable[*vi])
+            .map(|(ui, vi, _)| (result.nodes[*ui].clone(), result.nodes[*vi].clone()))
+            .collect();
+
+        (result.total_flow, cut_edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_flow_simple_diamond() {
+        let mut gr: TinyDiGraph<i32, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2, 3]);
+        gr.add_edge(&0, &1, 3.0);
+        gr.add_edge(&0, &2, 2.0);
+        gr.add_edge(&1, &3, 2.0);
+        gr.add_edge(&2, &3, 3.0);
+
+        let (value, flow) = gr.max_flow(&0, &3, |_u, _v, cap| *cap);
+        assert_eq!(value, 4.0);
+        assert!(flow[&(0, 1)] <= 3.0);
+        assert!(flow[&(0, 2)] <= 2.0);
+        assert_eq!(flow[&(0, 1)] + flow[&(0, 2)], 4.0);
+    }
+
+    #[test]
+    fn test_min_cut_matches_max_flow_value() {
+        let mut gr: TinyDiGraph<i32, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2, 3]);
+        gr.add_edge(&0, &1, 1.0);
+        gr.add_edge(&1, &2, 5.0);
+        gr.add_edge(&2, &3, 1.0);
+
+        let (flow_value, _) = gr.max_flow(&0, &3, |_u, _v, cap| *cap);
+        let (cut_value, cut_edges) = gr.min_cut(&0, &3, |_u, _v, cap| *cap);
+
+        assert_eq!(flow_value, cut_value);
+        assert_eq!(cut_value, 1.0);
+        assert!(cut_edges == vec![(0, 1)] || cut_edges == vec![(2, 3)]);
+    }
+}
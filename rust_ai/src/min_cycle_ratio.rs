@@ -13,66 +13,82 @@ use crate::types::{Cycle, Domain, Edge, Node, RatioType};
 /// Cycle Ratio API for parametric cycle ratio calculations.
 ///
 /// This struct implements the parametric API for cycle ratio calculations.
-/// It provides methods to compute distances based on a given ratio and to
-/// calculate the actual ratio for a given cycle.
-pub struct CycleRatioAPI<N, E, R>
+/// Rather than hardcoding `edge["cost"]`/`edge["time"]` lookups (which forced
+/// every edge type to be a string-keyed map), it takes arbitrary `numerator`
+/// and `denominator` accessor closures, so callers can solve cost-to-time
+/// ratio problems on custom edge structs, tuples, or differently named
+/// fields, and can express a profit-to-time maximization or delay-to-
+/// throughput ratio just by swapping the two accessors.
+pub struct CycleRatioAPI<N, E, R, FNum, FDen>
 where
     N: Node,
     E: Edge,
     R: RatioType,
+    FNum: Fn(&E) -> R,
+    FDen: Fn(&E) -> R,
 {
     /// The graph structure where nodes map to neighbors and edge attributes
     _digraph: HashMap<N, HashMap<N, E>>,
-    /// Marker for result type
-    _marker: std::marker::PhantomData<R>,
+    /// Accessor for the ratio's numerator (e.g. cost)
+    numerator: FNum,
+    /// Accessor for the ratio's denominator (e.g. time)
+    denominator: FDen,
+    /// Marker for unused type parameter N
+    _marker: std::marker::PhantomData<N>,
 }
 
-impl<N, E, R> CycleRatioAPI<N, E, R>
+impl<N, E, R, FNum, FDen> CycleRatioAPI<N, E, R, FNum, FDen>
 where
     N: Node,
     E: Edge,
     R: RatioType,
+    FNum: Fn(&E) -> R,
+    FDen: Fn(&E) -> R,
 {
-    /// Initialize the CycleRatioAPI with a graph.
+    /// Initialize the CycleRatioAPI with a graph and accessor closures.
     ///
     /// # Arguments
     ///
     /// * `digraph` - The graph structure where nodes map to neighbors and edge attributes
-    pub fn new(digraph: HashMap<N, HashMap<N, E>>) -> Self {
+    /// * `numerator` - Accessor for the ratio's numerator (e.g. cost)
+    /// * `denominator` - Accessor for the ratio's denominator (e.g. time)
+    pub fn new(digraph: HashMap<N, HashMap<N, E>>, numerator: FNum, denominator: FDen) -> Self {
         Self {
             _digraph: digraph,
+            numerator,
+            denominator,
             _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<N, E, R> ParametricAPI<N, E, R> for CycleRatioAPI<N, E, R>
+impl<N, E, R, FNum, FDen> ParametricAPI<N, E, R> for CycleRatioAPI<N, E, R, FNum, FDen>
 where
     N: Node,
-    E: Edge + std::ops::Index<&'static str, Output = R>,
+    E: Edge,
     R: RatioType + Clone,
+    FNum: Fn(&E) -> R,
+    FDen: Fn(&E) -> R,
 {
     /// Calculate the parametric distance for an edge given the current ratio.
     ///
-    /// The distance formula is: cost - ratio * time
+    /// The distance formula is: numerator - ratio * denominator
     ///
     /// # Arguments
     ///
     /// * `ratio` - The current ratio value being tested
-    /// * `edge` - The edge with 'cost' and 'time' attributes
+    /// * `edge` - The edge to evaluate
     ///
     /// # Returns
     ///
     /// The calculated distance value.
     fn distance(&self, ratio: R, edge: &E) -> R {
-        let cost = &edge["cost"];
-        let time = &edge["time"];
-        cost.clone() - ratio * time.clone()
+        (self.numerator)(edge) - ratio * (self.denominator)(edge)
     }
 
     /// Calculate the actual ratio for a given cycle.
     ///
-    /// The ratio is computed as: total_cost / total_time
+    /// The ratio is computed as: total_numerator / total_denominator
     ///
     /// # Arguments
     ///
@@ -82,15 +98,17 @@ where
     ///
     /// The calculated cycle ratio.
     fn zero_cancel(&self, cycle: &Cycle<E>) -> R {
-        let total_cost: R = cycle.iter()
-            .map(|edge| edge["cost"].clone())
+        let total_numerator: R = cycle
+            .iter()
+            .map(|edge| (self.numerator)(edge))
             .fold(R::zero(), |acc, x| acc + x);
 
-        let total_time: R = cycle.iter()
-            .map(|edge| edge["time"].clone())
+        let total_denominator: R = cycle
+            .iter()
+            .map(|edge| (self.denominator)(edge))
             .fold(R::zero(), |acc, x| acc + x);
 
-        total_cost / total_time
+        total_numerator / total_denominator
     }
 }
 
@@ -141,7 +159,7 @@ where
     /// Run the minimum cycle ratio solver algorithm.
     ///
     /// The algorithm works by:
-    /// 1. Creating a CycleRatioAPI instance with the graph
+    /// 1. Creating a CycleRatioAPI instance with the graph and accessors
     /// 2. Using a MaxParametricSolver to find the optimal ratio
     /// 3. Returning both the optimal ratio and the corresponding cycle
     ///
@@ -149,16 +167,25 @@ where
     ///
     /// * `dist` - Initial distance labels for nodes
     /// * `r0` - Initial ratio value to start the search
+    /// * `numerator` - Accessor for the ratio's numerator (e.g. cost)
+    /// * `denominator` - Accessor for the ratio's denominator (e.g. time)
     ///
     /// # Returns
     ///
     /// A tuple containing the optimal ratio and the cycle that achieves it.
-    pub fn run(&self, dist: HashMap<N, R>, r0: R) -> (R, Cycle<E>)
+    pub fn run<FNum, FDen>(
+        &self,
+        dist: HashMap<N, R>,
+        r0: R,
+        numerator: FNum,
+        denominator: FDen,
+    ) -> (R, Cycle<E>)
     where
         R: Domain + Clone,
-        E: Edge + std::ops::Index<&'static str, Output = R>,
+        FNum: Fn(&E) -> R,
+        FDen: Fn(&E) -> R,
     {
-        let omega = CycleRatioAPI::new(self.digraph.clone());
+        let omega = CycleRatioAPI::new(self.digraph.clone(), numerator, denominator);
         let solver = MaxParametricSolver::new(self.digraph.clone(), omega);
         solver.run(dist, r0)
     }
@@ -196,20 +223,36 @@ mod tests {
     use std::collections::HashMap;
 
     #[test]
-    fn test_cycle_ratio_api_new() {
-        // Simple test with basic types
-        let _digraph: HashMap<&str, HashMap<&str, i32>> = HashMap::new();
-        // Note: CycleRatioAPI requires Edge type with specific fields
-        // This test just verifies basic compilation
-        assert!(true);
+    fn test_cycle_ratio_api_with_tuple_edges() {
+        let digraph: HashMap<&str, HashMap<&str, (f64, f64)>> = HashMap::new();
+        let api = CycleRatioAPI::new(digraph, |e: &(f64, f64)| e.0, |e: &(f64, f64)| e.1);
+
+        let cycle = vec![(1.0, 1.0), (2.0, 1.0), (3.0, 1.0)];
+        assert_eq!(api.zero_cancel(&cycle), 2.0);
     }
 
     #[test]
-    fn test_min_cycle_ratio_solver_new() {
-        // Simple test with basic types
-        let _digraph: HashMap<&str, HashMap<&str, i32>> = HashMap::new();
-        let _solver: MinCycleRatioSolver<&str, i32, f64> = MinCycleRatioSolver::new(_digraph);
-        // Just testing that it compiles and creates successfully
-        assert!(true);
+    fn test_min_cycle_ratio_solver_run() {
+        let mut digraph: HashMap<&str, HashMap<&str, (f64, f64)>> = HashMap::new();
+        let mut a = HashMap::new();
+        a.insert("b", (1.0, 1.0));
+        digraph.insert("a", a);
+        let mut b = HashMap::new();
+        b.insert("c", (2.0, 1.0));
+        digraph.insert("b", b);
+        let mut c = HashMap::new();
+        c.insert("a", (3.0, 1.0));
+        digraph.insert("c", c);
+
+        let mut dist = HashMap::new();
+        dist.insert("a", 0.0);
+        dist.insert("b", 0.0);
+        dist.insert("c", 0.0);
+
+        let solver: MinCycleRatioSolver<&str, (f64, f64), f64> = MinCycleRatioSolver::new(digraph);
+        let (ratio, cycle) = solver.run(dist, 10.0, |e| e.0, |e| e.1);
+
+        assert!((ratio - 2.0).abs() < 1e-6);
+        assert_eq!(cycle.len(), 3);
     }
 }
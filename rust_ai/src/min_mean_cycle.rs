@@ -0,0 +1,266 @@
+//! Minimum Mean Cycle Solver
+//!
+//! This module implements Karp's algorithm for the minimum mean cycle
+//! problem: finding the cycle minimizing total weight divided by its number
+//! of edges, computed directly rather than through the parametric
+//! `cost`/`time` machinery used by `MinCycleRatioSolver`. For a unit-time
+//! graph this gives users an exact, non-iterative answer without needing to
+//! seed an initial ratio.
+
+use std::collections::HashMap;
+
+use crate::types::{Cycle, Domain, Edge, Node};
+
+/// Minimum Mean Cycle Solver via Karp's dynamic program.
+///
+/// For a start node `s`, `D_k(v)` is the minimum weight of a walk with
+/// exactly `k` edges from `s` to `v` (`D_0(s) = 0`, all other `D_0 =
+/// infinity`). The optimal mean is
+/// `lambda* = min over v of max over 0<=k<n of (D_n(v) - D_k(v)) / (n - k)`,
+/// ignoring `v` for which `D_n(v)` is unreachable. The solver runs this per
+/// strongly connected component so disconnected or unreachable nodes don't
+/// corrupt the result.
+pub struct MinMeanCycleSolver<N, E, D>
+where
+    N: Node,
+    E: Edge,
+    D: Domain,
+{
+    /// The graph structure where nodes map to neighbors and edge attributes
+    digraph: HashMap<N, HashMap<N, E>>,
+    /// Marker for unused type parameter D
+    _marker: std::marker::PhantomData<D>,
+}
+
+impl<N, E, D> MinMeanCycleSolver<N, E, D>
+where
+    N: Node,
+    E: Edge,
+    D: Domain,
+{
+    /// Initialize the solver with the graph to analyze.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph` - The graph structure where nodes map to neighbors and edge attributes
+    pub fn new(digraph: HashMap<N, HashMap<N, E>>) -> Self {
+        Self {
+            digraph,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Run the minimum mean cycle solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `get_weight` - Function to get the weight of an edge
+    ///
+    /// # Returns
+    ///
+    /// The minimum mean `(lambda*, cycle)`, or `None` if the graph has no
+    /// cycle at all.
+    pub fn run(&self, get_weight: impl Fn(&E) -> D) -> Option<(D, Cycle<E>)> {
+        let mut best: Option<(D, Cycle<E>)> = None;
+
+        for component in self.strongly_connected_components() {
+            if component.len() == 1 {
+                let v = &component[0];
+                let has_self_loop = self
+                    .digraph
+                    .get(v)
+                    .map(|neighbors| neighbors.contains_key(v))
+                    .unwrap_or(false);
+                if !has_self_loop {
+                    continue;
+                }
+            }
+
+            if let Some((lambda, cycle)) = self.min_mean_cycle_in_component(&component, &get_weight) {
+                let better = match &best {
+                    Some((best_lambda, _)) => lambda < *best_lambda,
+                    None => true,
+                };
+                if better {
+                    best = Some((lambda, cycle));
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Karp's dynamic program restricted to a single strongly connected
+    /// component, reconstructing a witnessing cycle from the predecessor
+    /// table.
+    fn min_mean_cycle_in_component(
+        &self,
+        component: &[N],
+        get_weight: &impl Fn(&E) -> D,
+    ) -> Option<(D, Cycle<E>)> {
+        let nodes = component.to_vec();
+        let n = nodes.len();
+        let start = 0usize;
+
+        let mut dist: Vec<Vec<Option<D>>> = vec![vec![None; n]; n + 1];
+        let mut pred: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n + 1];
+        dist[0][start] = Some(D::zero());
+
+        for k in 1..=n {
+            for (v_idx, v) in nodes.iter().enumerate() {
+                for (u_idx, u) in nodes.iter().enumerate() {
+                    let Some(edge) = self.digraph.get(u).and_then(|m| m.get(v)) else {
+                        continue;
+                    };
+                    let Some(prev) = &dist[k - 1][u_idx] else {
+                        continue;
+                    };
+                    let candidate = prev.clone() + get_weight(edge);
+                    let better = match &dist[k][v_idx] {
+                        Some(existing) => candidate < *existing,
+                        None => true,
+                    };
+                    if better {
+                        dist[k][v_idx] = Some(candidate);
+                        pred[k][v_idx] = Some(u_idx);
+                    }
+                }
+            }
+        }
+
+        let mut best_lambda: Option<D> = None;
+        let mut best_v: Option<usize> = None;
+
+        for v_idx in 0..n {
+            let Some(d_n) = &dist[n][v_idx] else {
+                continue;
+            };
+
+            let mut max_ratio: Option<D> = None;
+            for (k, d_k_row) in dist.iter().enumerate().take(n) {
+                let Some(d_k) = &d_k_row[v_idx] else {
+                    continue;
+                };
+                let numerator = d_n.clone() - d_k.clone();
+                let mut denominator = D::zero();
+                for _ in 0..(n - k) {
+                    denominator = denominator + D::one();
+                }
+                let ratio = numerator / denominator;
+                max_ratio = Some(match max_ratio {
+                    Some(existing) if existing > ratio => existing,
+                    _ => ratio,
+                });
+            }
+
+            if let Some(ratio) = max_ratio {
+                let better = match &best_lambda {
+                    Some(existing) => ratio < *existing,
+                    None => true,
+                };
+                if better {
+                    best_lambda = Some(ratio);
+                    best_v = Some(v_idx);
+                }
+            }
+        }
+
+        let lambda = best_lambda?;
+        let mut v_idx = best_v?;
+
+        // Trace n steps of predecessors from (n, v_idx); by pigeonhole, this
+        // walk over only n distinct nodes must repeat one, and the loop
+        // between the repeat is a witnessing minimum mean cycle.
+        let mut walk = vec![v_idx];
+        for k in (1..=n).rev() {
+            v_idx = pred[k][v_idx]?;
+            walk.push(v_idx);
+        }
+        walk.reverse();
+
+        let mut first_seen: HashMap<usize, usize> = HashMap::new();
+        let mut cycle_nodes: Vec<usize> = Vec::new();
+        for (i, &idx) in walk.iter().enumerate() {
+            if let Some(&j) = first_seen.get(&idx) {
+                cycle_nodes = walk[j..=i].to_vec();
+                break;
+            }
+            first_seen.insert(idx, i);
+        }
+
+        let cycle: Cycle<E> = cycle_nodes
+            .windows(2)
+            .map(|pair| self.digraph[&nodes[pair[0]]][&nodes[pair[1]]].clone())
+            .collect();
+
+        Some((lambda, cycle))
+    }
+
+    /// Computes the strongly connected components of `digraph` via an
+    /// iterative Tarjan's algorithm.
+    fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let nodes: Vec<N> = self.digraph.keys().cloned().collect();
+        let index_of: HashMap<N, usize> = nodes
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+        let adj: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|u| {
+                self.digraph
+                    .get(u)
+                    .map(|neighbors| {
+                        neighbors
+                            .keys()
+                            .filter_map(|v| index_of.get(v).copied())
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        crate::scc::tarjan_scc(&adj)
+            .into_iter()
+            .map(|component| component.into_iter().map(|i| nodes[i].clone()).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_mean_cycle_triangle() {
+        let mut digraph: HashMap<&str, HashMap<&str, f64>> = HashMap::new();
+        let mut a = HashMap::new();
+        a.insert("b", 1.0);
+        digraph.insert("a", a);
+        let mut b = HashMap::new();
+        b.insert("c", 2.0);
+        digraph.insert("b", b);
+        let mut c = HashMap::new();
+        c.insert("a", 3.0);
+        digraph.insert("c", c);
+
+        let solver = MinMeanCycleSolver::new(digraph);
+        let (lambda, cycle) = solver.run(|e| *e).expect("a cycle exists");
+
+        assert!((lambda - 2.0).abs() < 1e-9);
+        assert_eq!(cycle.len(), 3);
+    }
+
+    #[test]
+    fn test_min_mean_cycle_no_cycle() {
+        let mut digraph: HashMap<&str, HashMap<&str, f64>> = HashMap::new();
+        let mut a = HashMap::new();
+        a.insert("b", 1.0);
+        digraph.insert("a", a);
+        digraph.insert("b", HashMap::new());
+
+        let solver = MinMeanCycleSolver::new(digraph);
+        assert!(solver.run(|e| *e).is_none());
+    }
+}
@@ -6,11 +6,19 @@
 //! various applications, such as detecting arbitrage opportunities in currency
 //! exchange rates.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::marker::PhantomData;
 
 use crate::types::{Cycle, Domain, Edge, Node};
 
+/// Error returned when a negative cycle makes shortest-path distances
+/// undefined, carrying the certifying cycle.
+#[derive(Debug, Clone)]
+pub struct NegativeCycle<E> {
+    /// The edges forming the certifying negative cycle.
+    pub cycle: Cycle<E>,
+}
+
 /// Negative Cycle Finder by Howard's method
 ///
 /// This struct is used to find negative cycles in a given directed graph.
@@ -49,7 +57,8 @@ where
     /// * `digraph` - A mapping representing a directed graph where:
     ///     - Keys are source nodes
     ///     - Values are mappings of destination nodes to edges
-    ///     Example: {u: {v: edge_uv, w: edge_uw}, v: {u: edge_vu}}
+    ///
+    ///   Example: {u: {v: edge_uv, w: edge_uw}, v: {u: edge_vu}}
     pub fn new(digraph: HashMap<N, HashMap<N, E>>) -> Self {
         Self {
             digraph,
@@ -243,6 +252,107 @@ where
 
         cycles
     }
+
+    /// Find negative cycles using an SLF/LLL deque-based relaxation instead
+    /// of repeatedly sweeping every edge in the graph.
+    ///
+    /// Maintains an active-node deque seeded with every node. Each pop first
+    /// applies the Large-Label-Last heuristic (if the front node's distance
+    /// exceeds the average distance of all queued nodes, it is rotated to
+    /// the back and the new front re-examined), then relaxes only that
+    /// node's out-edges. Newly-improved neighbors are enqueued with the
+    /// Small-Label-First heuristic: pushed to the front if their new
+    /// distance is smaller than the current front node's, otherwise to the
+    /// back. Since this does not converge when a negative cycle exists, the
+    /// predecessor graph is periodically scanned for one via `find_cycle`
+    /// rather than waiting for the deque to empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Initial distance estimates (often initialized to zero)
+    /// * `get_weight` - Function to get edge weights
+    ///
+    /// # Returns
+    ///
+    /// A vector of found negative cycles, each as a list of edges.
+    pub fn howard_deque<F>(&mut self, dist: &mut HashMap<N, D>, get_weight: F) -> Vec<Cycle<E>>
+    where
+        F: Fn(&E) -> D + Clone,
+    {
+        self.pred.clear();
+
+        let mut queue: VecDeque<N> = self.digraph.keys().cloned().collect();
+        let mut queued: HashSet<N> = queue.iter().cloned().collect();
+        let check_interval = self.digraph.len().max(1);
+        let mut steps_since_check = 0usize;
+
+        while !queue.is_empty() {
+            // Large-Label-Last: rotate an overly expensive front to the back.
+            while queue.len() > 1 {
+                let total = queue.iter().fold(D::zero(), |acc, v| {
+                    acc + dist.get(v).cloned().unwrap_or_else(D::zero)
+                });
+                let mut count = D::zero();
+                for _ in 0..queue.len() {
+                    count = count + D::one();
+                }
+                let average = total / count;
+
+                let front = queue.front().expect("queue checked non-empty").clone();
+                let front_dist = dist.get(&front).cloned().unwrap_or_else(D::zero);
+                if front_dist > average {
+                    queue.rotate_left(1);
+                } else {
+                    break;
+                }
+            }
+
+            let utx = queue.pop_front().expect("queue checked non-empty");
+            queued.remove(&utx);
+            let dist_u = dist.get(&utx).cloned().unwrap_or_else(D::zero);
+
+            if let Some(neighbors) = self.digraph.get(&utx).cloned() {
+                for (vtx, edge) in neighbors {
+                    let weight = get_weight(&edge);
+                    let distance = dist_u.clone() + weight;
+                    let dist_v = dist.entry(vtx.clone()).or_insert_with(D::zero);
+
+                    if *dist_v > distance.clone() {
+                        *dist_v = distance.clone();
+                        self.pred.insert(vtx.clone(), (utx.clone(), edge));
+
+                        if !queued.contains(&vtx) {
+                            queued.insert(vtx.clone());
+                            let push_front = queue
+                                .front()
+                                .map(|f| distance < dist.get(f).cloned().unwrap_or_else(D::zero))
+                                .unwrap_or(true);
+                            if push_front {
+                                queue.push_front(vtx);
+                            } else {
+                                queue.push_back(vtx);
+                            }
+                        }
+                    }
+                }
+            }
+
+            steps_since_check += 1;
+            if steps_since_check >= check_interval {
+                steps_since_check = 0;
+                let mut cycles = Vec::new();
+                for vtx in self.find_cycle() {
+                    assert!(self.is_negative(&vtx, dist, &get_weight));
+                    cycles.push(self.cycle_list(&vtx));
+                }
+                if !cycles.is_empty() {
+                    return cycles;
+                }
+            }
+        }
+
+        Vec::new()
+    }
 }
 
 #[cfg(test)]
@@ -268,9 +378,7 @@ mod tests {
         neighbors.insert("c", 2);
         digraph.insert("b", neighbors);
 
-        let mut neighbors = HashMap::new();
-        neighbors.insert("a", -5);
-        digraph.insert("c", neighbors);
+        digraph.insert("c", HashMap::new());
 
         let mut finder: NegCycleFinder<&str, i32, i32> = NegCycleFinder::new(digraph);
         let mut dist = HashMap::new();
@@ -280,6 +388,14 @@ mod tests {
 
         let changed = finder.relax(&mut dist, |edge| *edge);
         assert!(changed);
+
+        // A single pass over a `HashMap` doesn't guarantee which edge gets
+        // relaxed first (e.g. whether `b`'s distance is updated before or
+        // after `c` is relaxed through it), so only the converged fixed
+        // point (reached once `relax` stops reporting changes) is
+        // order-independent. This graph is acyclic, so that fixed point is
+        // reached in finitely many passes.
+        while finder.relax(&mut dist, |edge| *edge) {}
         assert_eq!(dist["b"], 1);
         assert_eq!(dist["c"], 3);
     }
@@ -341,4 +457,30 @@ mod tests {
         // With this graph, we should find a negative cycle
         assert!(!cycles.is_empty());
     }
+
+    #[test]
+    fn test_howard_deque() {
+        let mut digraph = HashMap::new();
+        let mut neighbors = HashMap::new();
+        neighbors.insert("b", 1.0);
+        neighbors.insert("c", 4.0);
+        digraph.insert("a", neighbors);
+
+        let mut neighbors = HashMap::new();
+        neighbors.insert("c", 2.0);
+        digraph.insert("b", neighbors);
+
+        let mut neighbors = HashMap::new();
+        neighbors.insert("a", -5.0);
+        digraph.insert("c", neighbors);
+
+        let mut finder: NegCycleFinder<&str, f64, f64> = NegCycleFinder::new(digraph);
+        let mut dist = HashMap::new();
+        dist.insert("a", 0.0);
+        dist.insert("b", 1e9);
+        dist.insert("c", 1e9);
+
+        let cycles = finder.howard_deque(&mut dist, |edge| *edge);
+        assert!(!cycles.is_empty());
+    }
 }
@@ -5,26 +5,79 @@
 //! directed graph. A negative cycle is a cycle in the graph where the sum of the
 //! edge weights is negative.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 
+use crate::digraph_adj::DiGraphAdj;
+use crate::floyd_warshall::floyd_warshall_all_pairs;
+use crate::neg_cycle::NegativeCycle;
 use crate::types::{Cycle, Domain, Edge, Node};
 
+/// Rotate a cycle's node list so its smallest node comes first, giving a
+/// canonical form for deduplicating the same cycle found from different
+/// start nodes (e.g. via [`NegCycleFinderQ::howard_pred_exhaustive`]).
+fn canonical_rotation<N: Ord + Clone>(nodes: &[N]) -> Vec<N> {
+    let min_idx = nodes
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+
+    nodes[min_idx..].iter().chain(&nodes[..min_idx]).cloned().collect()
+}
+
+/// Recover the directed `(source, target)` pair consumed by each edge of a
+/// cycle found via predecessor links, in the same `(source, target)`
+/// orientation as `self.digraph`, so they can be added to an `excluded` set.
+///
+/// `nodes[i]` is the edge source reconstructed by
+/// [`NegCycleFinderQ::cycle_with_nodes_pred`]; its target is whichever node
+/// preceded it in that walk, which is `handle` for the first edge.
+fn cycle_edge_pairs_pred<N: Clone>(handle: &N, nodes: &[N]) -> Vec<(N, N)> {
+    let mut pairs = Vec::with_capacity(nodes.len());
+    let mut target = handle.clone();
+    for source in nodes {
+        pairs.push((source.clone(), target));
+        target = source.clone();
+    }
+    pairs
+}
+
+/// Recover the directed `(source, target)` pair consumed by each edge of a
+/// cycle found via successor links, in the same `(source, target)`
+/// orientation as `self.digraph`, so they can be added to an `excluded` set.
+///
+/// `nodes[i]` is the edge target reconstructed by
+/// [`NegCycleFinderQ::cycle_with_nodes_succ`]; its source is whichever node
+/// preceded it in that walk, which is `handle` for the first edge.
+fn cycle_edge_pairs_succ<N: Clone>(handle: &N, nodes: &[N]) -> Vec<(N, N)> {
+    let mut pairs = Vec::with_capacity(nodes.len());
+    let mut source = handle.clone();
+    for target in nodes {
+        pairs.push((source, target.clone()));
+        source = target.clone();
+    }
+    pairs
+}
+
 /// Negative Cycle Finder with constraints using Howard's method
 ///
 /// This struct implements Howard's method, which is a minimum cycle ratio (MCR) algorithm.
 /// It works by maintaining a set of candidate cycles and iteratively updating them until
 /// it finds the minimum cycle ratio or detects a negative cycle.
-pub struct NegCycleFinderQ<N, E, D>
+pub struct NegCycleFinderQ<N, E, D, G = HashMap<N, HashMap<N, E>>>
 where
     N: Node,
     E: Edge,
     D: Domain,
+    G: DiGraphAdj<N, E>,
 {
-    /// The directed graph where:
-    /// - Keys are source nodes
-    /// - Values are mappings of destination nodes to edges
-    digraph: HashMap<N, HashMap<N, E>>,
+    /// The directed graph, accessed only through [`DiGraphAdj`] so that `G`
+    /// can be the owned `HashMap<N, HashMap<N, E>>` representation, a
+    /// `TinyDiGraph`, or even a borrowed `&TinyDiGraph`, without ever being
+    /// copied into a different shape first.
+    digraph: G,
     /// Dictionary to store predecessor information (node -> (predecessor_node, edge))
     pred: HashMap<N, (N, E)>,
     /// Dictionary to store successor information (node -> (successor_node, edge))
@@ -33,20 +86,22 @@ where
     _marker: PhantomData<D>,
 }
 
-impl<N, E, D> NegCycleFinderQ<N, E, D>
+impl<N, E, D, G> NegCycleFinderQ<N, E, D, G>
 where
     N: Node,
     E: Edge,
     D: Domain,
+    G: DiGraphAdj<N, E>,
 {
-    /// Initialize the negative cycle finder with a directed graph.
+    /// Initialize the negative cycle finder with any graph implementing
+    /// [`DiGraphAdj`], such as the nested `HashMap<N, HashMap<N, E>>`
+    /// representation, a [`TinyDiGraph`](crate::tiny_digraph::TinyDiGraph),
+    /// or a `&TinyDiGraph`.
     ///
     /// # Arguments
     ///
-    /// * `digraph` - A mapping representing a directed graph where:
-    ///     - Keys are source nodes
-    ///     - Values are mappings of destination nodes to edges
-    pub fn new(digraph: HashMap<N, HashMap<N, E>>) -> Self {
+    /// * `digraph` - Any adjacency-providing graph
+    pub fn new(digraph: G) -> Self {
         Self {
             digraph,
             pred: HashMap::new(),
@@ -55,6 +110,17 @@ where
         }
     }
 
+    /// Alias for [`NegCycleFinderQ::new`], kept for callers that prefer to
+    /// spell out that `graph` is being analyzed via [`DiGraphAdj`] rather
+    /// than taken by its concrete type.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - Any adjacency-providing graph
+    pub fn from_adj(graph: G) -> Self {
+        Self::new(graph)
+    }
+
     /// Find cycles in the current predecessor graph using depth-first search.
     ///
     /// Uses a coloring algorithm (white/gray/black) to detect cycles.
@@ -67,7 +133,7 @@ where
         let mut result = Vec::new();
 
         // Collect keys first to avoid borrowing issues
-        let keys: Vec<N> = self.digraph.keys().cloned().collect();
+        let keys: Vec<N> = self.digraph.nodes().cloned().collect();
 
         for vtx in keys {
             if visited.contains_key(&vtx) {
@@ -106,7 +172,7 @@ where
         let mut result = Vec::new();
 
         // Collect keys first to avoid borrowing issues
-        let keys: Vec<N> = self.digraph.keys().cloned().collect();
+        let keys: Vec<N> = self.digraph.nodes().cloned().collect();
 
         for vtx in keys {
             if visited.contains_key(&vtx) {
@@ -151,17 +217,22 @@ where
     {
         let mut changed = false;
 
-        for (utx, neighbors) in &self.digraph {
-            let dist_u = dist.get(utx).cloned().unwrap_or_else(D::zero);
+        for utx in self.digraph.nodes().cloned().collect::<Vec<_>>() {
+            let dist_u = dist.get(&utx).cloned().unwrap_or_else(D::zero);
+            let edges: Vec<(N, E)> = self
+                .digraph
+                .out_edges(&utx)
+                .map(|(v, e)| (v.clone(), e.clone()))
+                .collect();
 
-            for (vtx, edge) in neighbors {
-                let weight = get_weight(edge);
+            for (vtx, edge) in edges {
+                let weight = get_weight(&edge);
                 let distance = dist_u.clone() + weight;
 
                 let dist_v = dist.entry(vtx.clone()).or_insert_with(D::zero);
                 if *dist_v > distance {
                     *dist_v = distance;
-                    self.pred.insert(vtx.clone(), (utx.clone(), edge.clone()));
+                    self.pred.insert(vtx, (utx.clone(), edge));
                     changed = true;
                 }
             }
@@ -187,21 +258,27 @@ where
         F: Fn(&E) -> D,
     {
         let mut changed = false;
+        let nodes: Vec<N> = self.digraph.nodes().cloned().collect();
 
-        for (vtx, _predecessors) in &self.digraph {
+        for vtx in &nodes {
             // We need to find edges that end at vtx
-            for (utx, neighbors) in &self.digraph {
-                if let Some(edge) = neighbors.get(vtx) {
-                    let dist_u = dist.get(utx).cloned().unwrap_or_else(D::zero);
-                    let weight = get_weight(edge);
-                    let distance = dist_u.clone() + weight;
-
-                    let dist_v = dist.entry(vtx.clone()).or_insert_with(D::zero);
-                    if *dist_v > distance {
-                        *dist_v = distance;
-                        self.succ.insert(utx.clone(), (vtx.clone(), edge.clone()));
-                        changed = true;
-                    }
+            for utx in &nodes {
+                let edge = self
+                    .digraph
+                    .out_edges(utx)
+                    .find(|(v, _)| *v == vtx)
+                    .map(|(_, e)| e.clone());
+                let Some(edge) = edge else { continue };
+
+                let dist_u = dist.get(utx).cloned().unwrap_or_else(D::zero);
+                let weight = get_weight(&edge);
+                let distance = dist_u.clone() + weight;
+
+                let dist_v = dist.entry(vtx.clone()).or_insert_with(D::zero);
+                if *dist_v > distance {
+                    *dist_v = distance;
+                    self.succ.insert(utx.clone(), (vtx.clone(), edge));
+                    changed = true;
                 }
             }
         }
@@ -263,6 +340,52 @@ where
         cycle
     }
 
+    /// Like [`NegCycleFinderQ::cycle_list_pred`], but also returns the
+    /// cycle's nodes in the same walk order, so callers can canonicalize the
+    /// cycle (e.g. for deduplication) without re-walking the predecessor
+    /// chain.
+    fn cycle_with_nodes_pred(&self, handle: &N, pred_map: &HashMap<N, (N, E)>) -> (Vec<N>, Cycle<E>) {
+        let mut nodes = Vec::new();
+        let mut cycle = Vec::new();
+        let mut vtx = handle.clone();
+
+        loop {
+            let (utx, edge) = pred_map.get(&vtx).expect("Node not in predecessor graph");
+            nodes.push(utx.clone());
+            cycle.push(edge.clone());
+            vtx = utx.clone();
+
+            if &vtx == handle {
+                break;
+            }
+        }
+
+        (nodes, cycle)
+    }
+
+    /// Like [`NegCycleFinderQ::cycle_list_succ`], but also returns the
+    /// cycle's nodes in the same walk order, so callers can canonicalize the
+    /// cycle (e.g. for deduplication) without re-walking the successor
+    /// chain.
+    fn cycle_with_nodes_succ(&self, handle: &N, succ_map: &HashMap<N, (N, E)>) -> (Vec<N>, Cycle<E>) {
+        let mut nodes = Vec::new();
+        let mut cycle = Vec::new();
+        let mut vtx = handle.clone();
+
+        loop {
+            let (next_vtx, edge) = succ_map.get(&vtx).expect("Node not in successor graph");
+            nodes.push(next_vtx.clone());
+            cycle.push(edge.clone());
+            vtx = next_vtx.clone();
+
+            if &vtx == handle {
+                break;
+            }
+        }
+
+        (nodes, cycle)
+    }
+
     /// Check if the cycle starting at 'handle' is negative using predecessor links.
     ///
     /// # Arguments
@@ -353,6 +476,12 @@ where
 
     /// Main algorithm to find negative cycles using Howard's method with predecessor relaxation.
     ///
+    /// Negative cycles can only live inside a single strongly connected
+    /// component, so this decomposes `digraph` via
+    /// [`NegCycleFinderQ::strongly_connected_components`] first and runs
+    /// relaxation separately per non-trivial component, skipping singletons
+    /// with no self-loop entirely.
+    ///
     /// # Arguments
     ///
     /// * `dist` - Initial distance estimates
@@ -367,13 +496,22 @@ where
     {
         let mut cycles = Vec::new();
         self.pred.clear();
-        let mut found = false;
 
-        while !found && self.relax_pred(dist, &get_weight) {
-            for vtx in self.find_cycle_pred() {
-                assert!(self.is_negative_pred(&vtx, dist, &get_weight, &self.pred));
-                found = true;
-                cycles.push(self.cycle_list_pred(&vtx, &self.pred));
+        for component in self.strongly_connected_components() {
+            if !self.has_internal_edge(&component) {
+                continue;
+            }
+
+            let members: HashSet<N> = component.iter().cloned().collect();
+            let no_excluded: HashSet<(N, N)> = HashSet::new();
+            let mut found = false;
+
+            while !found && self.relax_pred_within(dist, &get_weight, &members, &no_excluded) {
+                for vtx in self.find_cycle_pred_within(&component) {
+                    assert!(self.is_negative_pred(&vtx, dist, &get_weight, &self.pred));
+                    found = true;
+                    cycles.push(self.cycle_list_pred(&vtx, &self.pred));
+                }
             }
         }
 
@@ -382,6 +520,12 @@ where
 
     /// Main algorithm to find negative cycles using Howard's method with successor relaxation.
     ///
+    /// Negative cycles can only live inside a single strongly connected
+    /// component, so this decomposes `digraph` via
+    /// [`NegCycleFinderQ::strongly_connected_components`] first and runs
+    /// relaxation separately per non-trivial component, skipping singletons
+    /// with no self-loop entirely.
+    ///
     /// # Arguments
     ///
     /// * `dist` - Initial distance estimates
@@ -396,18 +540,515 @@ where
     {
         let mut cycles = Vec::new();
         self.succ.clear();
-        let mut found = false;
 
-        while !found && self.relax_succ(dist, &get_weight) {
-            for vtx in self.find_cycle_succ() {
-                assert!(self.is_negative_succ(&vtx, dist, &get_weight, &self.succ));
-                found = true;
-                cycles.push(self.cycle_list_succ(&vtx, &self.succ));
+        for component in self.strongly_connected_components() {
+            if !self.has_internal_edge(&component) {
+                continue;
+            }
+
+            let members: HashSet<N> = component.iter().cloned().collect();
+            let no_excluded: HashSet<(N, N)> = HashSet::new();
+            let mut found = false;
+
+            while !found && self.relax_succ_within(dist, &get_weight, &members, &no_excluded) {
+                for vtx in self.find_cycle_succ_within(&component) {
+                    assert!(self.is_negative_succ(&vtx, dist, &get_weight, &self.succ));
+                    found = true;
+                    cycles.push(self.cycle_list_succ(&vtx, &self.succ));
+                }
             }
         }
 
         cycles
     }
+
+    /// Enumerate every distinct negative cycle reachable by predecessor
+    /// relaxation, instead of stopping at the first batch found by
+    /// [`NegCycleFinderQ::howard_pred`].
+    ///
+    /// Relaxation only ever maintains a single predecessor per node, so once
+    /// it locks onto one negative cycle through a shared node, a second
+    /// cycle through that same node never resurfaces by simply continuing to
+    /// relax — its nodes keep routing through the first cycle's (ever more
+    /// negative) predecessor instead. So after each cycle is found, its
+    /// edges are added to an `excluded` set and predecessors/distances for
+    /// the component are rebuilt from scratch excluding them, forcing the
+    /// next search to route around it. This repeats per component until a
+    /// from-scratch relaxation finds no cycle at all, which must happen
+    /// eventually since each round removes at least one edge from
+    /// consideration. Cycles are deduplicated by their canonical rotation
+    /// (nodes rotated so the smallest node comes first) as a safety net,
+    /// since a single relaxation pass can occasionally surface more than one
+    /// cycle at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Initial distance estimates
+    /// * `get_weight` - Function to get edge weights
+    ///
+    /// # Returns
+    ///
+    /// Each distinct negative cycle found, paired with its total weight
+    /// under `get_weight`.
+    pub fn howard_pred_exhaustive<F>(
+        &mut self,
+        dist: &mut HashMap<N, D>,
+        get_weight: F,
+    ) -> Vec<(Cycle<E>, D)>
+    where
+        N: Ord,
+        F: Fn(&E) -> D + Clone,
+    {
+        let mut seen: HashSet<Vec<N>> = HashSet::new();
+        let mut results = Vec::new();
+
+        for component in self.strongly_connected_components() {
+            if !self.has_internal_edge(&component) {
+                continue;
+            }
+
+            let members: HashSet<N> = component.iter().cloned().collect();
+            let mut excluded: HashSet<(N, N)> = HashSet::new();
+            let max_passes = members.len() + 1;
+
+            loop {
+                self.pred.clear();
+                let mut local_dist: HashMap<N, D> = component
+                    .iter()
+                    .map(|n| (n.clone(), dist.get(n).cloned().unwrap_or_else(D::zero)))
+                    .collect();
+
+                let mut found_this_round = false;
+                let mut pass = 0;
+
+                while pass <= max_passes
+                    && self.relax_pred_within(&mut local_dist, &get_weight, &members, &excluded)
+                {
+                    pass += 1;
+
+                    for vtx in self.find_cycle_pred_within(&component) {
+                        assert!(self.is_negative_pred(&vtx, &local_dist, &get_weight, &self.pred));
+                        let (nodes, cycle) = self.cycle_with_nodes_pred(&vtx, &self.pred);
+
+                        for edge_pair in cycle_edge_pairs_pred(&vtx, &nodes) {
+                            excluded.insert(edge_pair);
+                        }
+                        found_this_round = true;
+
+                        if seen.insert(canonical_rotation(&nodes)) {
+                            let weight =
+                                cycle.iter().map(&get_weight).fold(D::zero(), |acc, x| acc + x);
+                            results.push((cycle, weight));
+                        }
+                    }
+
+                    if found_this_round {
+                        break;
+                    }
+                }
+
+                for node in &component {
+                    if let Some(d) = local_dist.get(node) {
+                        dist.insert(node.clone(), d.clone());
+                    }
+                }
+
+                if !found_this_round {
+                    break;
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Enumerate every distinct negative cycle reachable by successor
+    /// relaxation, instead of stopping at the first batch found by
+    /// [`NegCycleFinderQ::howard_succ`].
+    ///
+    /// See [`NegCycleFinderQ::howard_pred_exhaustive`] for why a found
+    /// cycle's edges must be excluded and the component re-relaxed from
+    /// scratch, rather than just continuing to relax; this is the same
+    /// algorithm mirrored onto the successor graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Initial distance estimates
+    /// * `get_weight` - Function to get edge weights
+    ///
+    /// # Returns
+    ///
+    /// Each distinct negative cycle found, paired with its total weight
+    /// under `get_weight`.
+    pub fn howard_succ_exhaustive<F>(
+        &mut self,
+        dist: &mut HashMap<N, D>,
+        get_weight: F,
+    ) -> Vec<(Cycle<E>, D)>
+    where
+        N: Ord,
+        F: Fn(&E) -> D + Clone,
+    {
+        let mut seen: HashSet<Vec<N>> = HashSet::new();
+        let mut results = Vec::new();
+
+        for component in self.strongly_connected_components() {
+            if !self.has_internal_edge(&component) {
+                continue;
+            }
+
+            let members: HashSet<N> = component.iter().cloned().collect();
+            let mut excluded: HashSet<(N, N)> = HashSet::new();
+            let max_passes = members.len() + 1;
+
+            loop {
+                self.succ.clear();
+                let mut local_dist: HashMap<N, D> = component
+                    .iter()
+                    .map(|n| (n.clone(), dist.get(n).cloned().unwrap_or_else(D::zero)))
+                    .collect();
+
+                let mut found_this_round = false;
+                let mut pass = 0;
+
+                while pass <= max_passes
+                    && self.relax_succ_within(&mut local_dist, &get_weight, &members, &excluded)
+                {
+                    pass += 1;
+
+                    for vtx in self.find_cycle_succ_within(&component) {
+                        assert!(self.is_negative_succ(&vtx, &local_dist, &get_weight, &self.succ));
+                        let (nodes, cycle) = self.cycle_with_nodes_succ(&vtx, &self.succ);
+
+                        for edge_pair in cycle_edge_pairs_succ(&vtx, &nodes) {
+                            excluded.insert(edge_pair);
+                        }
+                        found_this_round = true;
+
+                        if seen.insert(canonical_rotation(&nodes)) {
+                            let weight =
+                                cycle.iter().map(&get_weight).fold(D::zero(), |acc, x| acc + x);
+                            results.push((cycle, weight));
+                        }
+                    }
+
+                    if found_this_round {
+                        break;
+                    }
+                }
+
+                for node in &component {
+                    if let Some(d) = local_dist.get(node) {
+                        dist.insert(node.clone(), d.clone());
+                    }
+                }
+
+                if !found_this_round {
+                    break;
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Computes the minimum cycle ratio via Lawler's parametric binary
+    /// search, using `howard_pred` as a pure negative-cycle existence
+    /// oracle.
+    ///
+    /// For a candidate `lambda`, the reduced weight `w_lambda(e) = c(e) -
+    /// lambda * t(e)` has a negative cycle exactly when some cycle has ratio
+    /// `(sum c) / (sum t) < lambda`. Bisecting `[lo, hi]` on that test
+    /// converges to `r* = min over cycles of (sum c) / (sum t)`, the minimum
+    /// cycle ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Initial distance estimates, reset to a fresh copy at each trial
+    /// * `cost` - Per-edge cost accessor `c(e)`
+    /// * `transit` - Per-edge strictly-positive transit accessor `t(e)`
+    /// * `lo` - Lower bound known to be feasible (no cycle has a smaller ratio)
+    /// * `hi` - Upper bound known to be infeasible (some cycle has a smaller ratio)
+    /// * `epsilon` - Stop once the search interval shrinks below this width
+    ///
+    /// # Returns
+    ///
+    /// The optimal ratio and the cycle witnessing it at the final feasible
+    /// `lambda`.
+    pub fn min_cycle_ratio<FC, FT>(
+        &mut self,
+        dist: &HashMap<N, D>,
+        cost: FC,
+        transit: FT,
+        mut lo: D,
+        mut hi: D,
+        epsilon: D,
+    ) -> (D, Cycle<E>)
+    where
+        FC: Fn(&E) -> D,
+        FT: Fn(&E) -> D,
+    {
+        let two = D::one() + D::one();
+        let mut cycle: Cycle<E> = Vec::new();
+
+        while hi.clone() - lo.clone() > epsilon {
+            let mid = (lo.clone() + hi.clone()) / two.clone();
+            let mut trial_dist = dist.clone();
+            let get_weight = |e: &E| cost(e) - mid.clone() * transit(e);
+            let cycles = self.howard_pred(&mut trial_dist, get_weight);
+
+            if let Some(ci) = cycles.into_iter().next() {
+                hi = mid;
+                cycle = ci;
+            } else {
+                lo = mid;
+            }
+        }
+
+        let ratio = if cycle.is_empty() {
+            lo
+        } else {
+            let total_cost: D = cycle.iter().map(&cost).fold(D::zero(), |acc, x| acc + x);
+            let total_transit: D = cycle.iter().map(&transit).fold(D::zero(), |acc, x| acc + x);
+            total_cost / total_transit
+        };
+
+        (ratio, cycle)
+    }
+
+    /// A dense `O(V^3)` alternative to [`NegCycleFinderQ::howard_pred`] via
+    /// Floyd-Warshall. [`floyd_warshall_all_pairs`] only knows the nested
+    /// `HashMap<N, HashMap<N, E>>` representation, so `G`'s adjacency is
+    /// materialized into one just for this call; unlike [`Self::digraph`]
+    /// itself, this copy is local to a single dense `O(V^3)` call rather
+    /// than living for the finder's whole lifetime. Useful when the graph
+    /// is small and the caller wants the full all-pairs distance table
+    /// anyway, instead of just a certifying cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `get_weight` - Function to get edge weights
+    ///
+    /// # Returns
+    ///
+    /// A map from `(source, target)` to shortest distance for every
+    /// reachable pair, or the first negative cycle found if the distances
+    /// are undefined.
+    pub fn floyd_warshall(
+        &self,
+        get_weight: impl Fn(&E) -> D,
+    ) -> Result<HashMap<(N, N), D>, NegativeCycle<E>> {
+        let digraph: HashMap<N, HashMap<N, E>> = self
+            .digraph
+            .nodes()
+            .map(|u| {
+                let neighbors: HashMap<N, E> = self
+                    .digraph
+                    .out_edges(u)
+                    .map(|(v, e)| (v.clone(), e.clone()))
+                    .collect();
+                (u.clone(), neighbors)
+            })
+            .collect();
+        floyd_warshall_all_pairs(&digraph, get_weight)
+    }
+
+    /// Whether `component` could possibly host a cycle: more than one node,
+    /// or a single node with a self-loop.
+    fn has_internal_edge(&self, component: &[N]) -> bool {
+        if component.len() > 1 {
+            return true;
+        }
+        let Some(v) = component.first() else {
+            return false;
+        };
+        self.digraph.out_edges(v).any(|(w, _)| w == v)
+    }
+
+    /// Same as [`NegCycleFinderQ::relax_pred`], but only relaxes edges whose
+    /// endpoints both lie in `members` and whose `(source, target)` pair is
+    /// not in `excluded`.
+    fn relax_pred_within<F>(
+        &mut self,
+        dist: &mut HashMap<N, D>,
+        get_weight: &F,
+        members: &HashSet<N>,
+        excluded: &HashSet<(N, N)>,
+    ) -> bool
+    where
+        F: Fn(&E) -> D,
+    {
+        let mut changed = false;
+
+        for utx in self.digraph.nodes().cloned().collect::<Vec<_>>() {
+            if !members.contains(&utx) {
+                continue;
+            }
+            let dist_u = dist.get(&utx).cloned().unwrap_or_else(D::zero);
+            let edges: Vec<(N, E)> = self
+                .digraph
+                .out_edges(&utx)
+                .map(|(v, e)| (v.clone(), e.clone()))
+                .collect();
+
+            for (vtx, edge) in edges {
+                if !members.contains(&vtx) || excluded.contains(&(utx.clone(), vtx.clone())) {
+                    continue;
+                }
+                let weight = get_weight(&edge);
+                let distance = dist_u.clone() + weight;
+
+                let dist_v = dist.entry(vtx.clone()).or_insert_with(D::zero);
+                if *dist_v > distance {
+                    *dist_v = distance;
+                    self.pred.insert(vtx, (utx.clone(), edge));
+                    changed = true;
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Same as [`NegCycleFinderQ::relax_succ`], but only relaxes edges whose
+    /// endpoints both lie in `members` and whose `(source, target)` pair is
+    /// not in `excluded`.
+    fn relax_succ_within<F>(
+        &mut self,
+        dist: &mut HashMap<N, D>,
+        get_weight: &F,
+        members: &HashSet<N>,
+        excluded: &HashSet<(N, N)>,
+    ) -> bool
+    where
+        F: Fn(&E) -> D,
+    {
+        let mut changed = false;
+        let nodes: Vec<N> = self.digraph.nodes().cloned().collect();
+
+        for vtx in &nodes {
+            if !members.contains(vtx) {
+                continue;
+            }
+            for utx in &nodes {
+                if !members.contains(utx) || excluded.contains(&(utx.clone(), vtx.clone())) {
+                    continue;
+                }
+                let edge = self
+                    .digraph
+                    .out_edges(utx)
+                    .find(|(v, _)| *v == vtx)
+                    .map(|(_, e)| e.clone());
+                let Some(edge) = edge else { continue };
+
+                let dist_u = dist.get(utx).cloned().unwrap_or_else(D::zero);
+                let weight = get_weight(&edge);
+                let distance = dist_u.clone() + weight;
+
+                let dist_v = dist.entry(vtx.clone()).or_insert_with(D::zero);
+                if *dist_v > distance {
+                    *dist_v = distance;
+                    self.succ.insert(utx.clone(), (vtx.clone(), edge));
+                    changed = true;
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Same as [`NegCycleFinderQ::find_cycle_pred`], but only scans `component`.
+    fn find_cycle_pred_within(&self, component: &[N]) -> Vec<N> {
+        let mut visited: HashMap<N, N> = HashMap::new();
+        let mut result = Vec::new();
+
+        for vtx in component {
+            if visited.contains_key(vtx) {
+                continue;
+            }
+
+            let mut utx = vtx.clone();
+            visited.insert(utx.clone(), vtx.clone());
+
+            while let Some((pred_node, _)) = self.pred.get(&utx) {
+                utx = pred_node.clone();
+
+                if let Some(root) = visited.get(&utx) {
+                    if root == vtx {
+                        result.push(utx.clone());
+                    }
+                    break;
+                }
+
+                visited.insert(utx.clone(), vtx.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Same as [`NegCycleFinderQ::find_cycle_succ`], but only scans `component`.
+    fn find_cycle_succ_within(&self, component: &[N]) -> Vec<N> {
+        let mut visited: HashMap<N, N> = HashMap::new();
+        let mut result = Vec::new();
+
+        for vtx in component {
+            if visited.contains_key(vtx) {
+                continue;
+            }
+
+            let mut utx = vtx.clone();
+            visited.insert(utx.clone(), vtx.clone());
+
+            while let Some((succ_node, _)) = self.succ.get(&utx) {
+                utx = succ_node.clone();
+
+                if let Some(root) = visited.get(&utx) {
+                    if root == vtx {
+                        result.push(utx.clone());
+                    }
+                    break;
+                }
+
+                visited.insert(utx.clone(), vtx.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Decomposes `digraph` into its strongly connected components via an
+    /// iterative Tarjan's algorithm: a DFS index counter and a `lowlink` per
+    /// node, an explicit stack with an on-stack flag, and a node rooting an
+    /// SCC whenever `lowlink == index`, at which point the stack is popped
+    /// down to it.
+    ///
+    /// # Returns
+    ///
+    /// Each strongly connected component as a list of its member nodes.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let nodes: Vec<N> = self.digraph.nodes().cloned().collect();
+        let index_of: HashMap<N, usize> = nodes
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+        let adj: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|u| {
+                self.digraph
+                    .out_edges(u)
+                    .filter_map(|(v, _)| index_of.get(v).copied())
+                    .collect()
+            })
+            .collect();
+
+        crate::scc::tarjan_scc(&adj)
+            .into_iter()
+            .map(|component| component.into_iter().map(|i| nodes[i].clone()).collect())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -434,9 +1075,7 @@ mod tests {
         neighbors.insert("c", 2);
         digraph.insert("b", neighbors);
 
-        let mut neighbors = HashMap::new();
-        neighbors.insert("a", -5);
-        digraph.insert("c", neighbors);
+        digraph.insert("c", HashMap::new());
 
         let mut finder: NegCycleFinderQ<&str, i32, i32> = NegCycleFinderQ::new(digraph);
         let mut dist = HashMap::new();
@@ -446,7 +1085,180 @@ mod tests {
 
         let changed = finder.relax_pred(&mut dist, |edge| *edge);
         assert!(changed);
+
+        // A single pass over a `HashMap` doesn't guarantee which edge gets
+        // relaxed first (e.g. whether `b`'s distance is updated before or
+        // after `c` is relaxed through it), so only the converged fixed
+        // point (reached once `relax_pred` stops reporting changes) is
+        // order-independent. This graph is acyclic, so that fixed point is
+        // reached in finitely many passes.
+        while finder.relax_pred(&mut dist, |edge| *edge) {}
         assert_eq!(dist["b"], 1);
         assert_eq!(dist["c"], 3);
     }
+
+    #[test]
+    fn test_min_cycle_ratio() {
+        // Edge carries (cost, time); r* = total_cost / total_time = 2.0.
+        let mut digraph: HashMap<&str, HashMap<&str, (f64, f64)>> = HashMap::new();
+        let mut a = HashMap::new();
+        a.insert("b", (1.0, 1.0));
+        digraph.insert("a", a);
+        let mut b = HashMap::new();
+        b.insert("c", (2.0, 1.0));
+        digraph.insert("b", b);
+        let mut c = HashMap::new();
+        c.insert("a", (3.0, 1.0));
+        digraph.insert("c", c);
+
+        let mut finder: NegCycleFinderQ<&str, (f64, f64), f64> = NegCycleFinderQ::new(digraph);
+        let mut dist: HashMap<&str, f64> = HashMap::new();
+        dist.insert("a", 0.0);
+        dist.insert("b", 0.0);
+        dist.insert("c", 0.0);
+
+        let (ratio, cycle) =
+            finder.min_cycle_ratio(&dist, |e| e.0, |e| e.1, 0.0, 10.0, 1e-6);
+
+        assert!((ratio - 2.0).abs() < 1e-3);
+        assert_eq!(cycle.len(), 3);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // a <-> b <-> c form one cycle; d is disconnected.
+        let mut digraph: HashMap<&str, HashMap<&str, i32>> = HashMap::new();
+        digraph.insert("a", HashMap::from([("b", 1)]));
+        digraph.insert("b", HashMap::from([("c", 1)]));
+        digraph.insert("c", HashMap::from([("a", 1)]));
+        digraph.insert("d", HashMap::new());
+
+        let finder: NegCycleFinderQ<&str, i32, i32> = NegCycleFinderQ::new(digraph);
+        let mut sccs = finder.strongly_connected_components();
+        sccs.sort_by_key(|c| c.len());
+
+        assert_eq!(sccs.len(), 2);
+        assert_eq!(sccs[0], vec!["d"]);
+        let mut cycle_members = sccs[1].clone();
+        cycle_members.sort();
+        assert_eq!(cycle_members, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_howard_pred_skips_disconnected_region() {
+        // a -> b -> c -> a is a negative cycle; d -> e is a separate acyclic region.
+        let mut digraph: HashMap<&str, HashMap<&str, f64>> = HashMap::new();
+        digraph.insert("a", HashMap::from([("b", 1.0)]));
+        digraph.insert("b", HashMap::from([("c", 1.0)]));
+        digraph.insert("c", HashMap::from([("a", -5.0)]));
+        digraph.insert("d", HashMap::from([("e", 1.0)]));
+        digraph.insert("e", HashMap::new());
+
+        let mut finder: NegCycleFinderQ<&str, f64, f64> = NegCycleFinderQ::new(digraph);
+        let mut dist: HashMap<&str, f64> = HashMap::new();
+        for node in ["a", "b", "c", "d", "e"] {
+            dist.insert(node, 1e9);
+        }
+        dist.insert("a", 0.0);
+
+        let cycles = finder.howard_pred(&mut dist, |w| *w);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 3);
+    }
+
+    #[test]
+    fn test_floyd_warshall_matches_howard_on_negative_cycle() {
+        let mut digraph: HashMap<&str, HashMap<&str, f64>> = HashMap::new();
+        digraph.insert("a", HashMap::from([("b", 1.0)]));
+        digraph.insert("b", HashMap::from([("c", 1.0)]));
+        digraph.insert("c", HashMap::from([("a", -5.0)]));
+
+        let finder: NegCycleFinderQ<&str, f64, f64> = NegCycleFinderQ::new(digraph);
+        let err = finder
+            .floyd_warshall(|w| *w)
+            .expect_err("a negative cycle should be detected");
+        assert_eq!(err.cycle.len(), 3);
+    }
+
+    #[test]
+    fn test_floyd_warshall_returns_all_pairs_distances() {
+        let mut digraph: HashMap<i32, HashMap<i32, f64>> = HashMap::new();
+        digraph.insert(0, HashMap::from([(1, 4.0), (2, 5.0)]));
+        digraph.insert(1, HashMap::from([(2, -2.0)]));
+        digraph.insert(2, HashMap::new());
+
+        let finder: NegCycleFinderQ<i32, f64, f64> = NegCycleFinderQ::new(digraph);
+        let distances = finder.floyd_warshall(|w| *w).expect("no negative cycle");
+        assert_eq!(distances[&(0, 2)], 2.0);
+    }
+
+    #[test]
+    fn test_from_adj_accepts_tiny_digraph() {
+        use crate::tiny_digraph::TinyDiGraph;
+
+        let mut gr: TinyDiGraph<&str, f64> = TinyDiGraph::new();
+        gr.init_nodes(vec!["a", "b", "c"]);
+        gr.add_edge(&"a", &"b", 1.0);
+        gr.add_edge(&"b", &"c", 1.0);
+        gr.add_edge(&"c", &"a", -5.0);
+
+        let mut finder: NegCycleFinderQ<&str, f64, f64, &TinyDiGraph<&str, f64>> =
+            NegCycleFinderQ::from_adj(&gr);
+        let mut dist: HashMap<&str, f64> = HashMap::new();
+        for node in ["a", "b", "c"] {
+            dist.insert(node, 1e9);
+        }
+        dist.insert("a", 0.0);
+
+        let cycles = finder.howard_pred(&mut dist, |w| *w);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 3);
+    }
+
+    #[test]
+    fn test_howard_pred_exhaustive_finds_both_independent_cycles_once_each() {
+        let mut digraph: HashMap<i32, HashMap<i32, f64>> = HashMap::new();
+        digraph.insert(0, HashMap::from([(1, 1.0)]));
+        digraph.insert(1, HashMap::from([(2, 1.0)]));
+        digraph.insert(2, HashMap::from([(0, -5.0)]));
+        digraph.insert(10, HashMap::from([(11, 1.0)]));
+        digraph.insert(11, HashMap::from([(10, -3.0)]));
+
+        let mut finder: NegCycleFinderQ<i32, f64, f64> = NegCycleFinderQ::new(digraph);
+        let mut dist: HashMap<i32, f64> = [0, 1, 2, 10, 11].iter().map(|&n| (n, 0.0)).collect();
+
+        let results = finder.howard_pred_exhaustive(&mut dist, |w| *w);
+        assert_eq!(results.len(), 2);
+
+        let mut weights: Vec<f64> = results.iter().map(|(_, w)| *w).collect();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(weights, vec![-3.0, -2.0]);
+    }
+
+    #[test]
+    fn test_howard_pred_exhaustive_finds_both_cycles_sharing_a_hub_in_one_scc() {
+        // 0 -> 1 -> 2 -> 0 (weight -5) and 0 -> 3 -> 4 -> 0 (weight -3) share
+        // hub node 0 and are mutually reachable through it, so they form a
+        // single strongly connected component. Continued relaxation alone
+        // would lock onto the more negative cycle and starve the other one's
+        // nodes out of the predecessor map; excluding a found cycle's edges
+        // and restarting is required to surface both.
+        let mut digraph: HashMap<i32, HashMap<i32, f64>> = HashMap::new();
+        digraph.insert(0, HashMap::from([(1, 0.0), (3, 0.0)]));
+        digraph.insert(1, HashMap::from([(2, 0.0)]));
+        digraph.insert(2, HashMap::from([(0, -5.0)]));
+        digraph.insert(3, HashMap::from([(4, 0.0)]));
+        digraph.insert(4, HashMap::from([(0, -3.0)]));
+
+        let mut finder: NegCycleFinderQ<i32, f64, f64> = NegCycleFinderQ::new(digraph);
+        let mut dist: HashMap<i32, f64> =
+            [0, 1, 2, 3, 4].iter().map(|&n| (n, 0.0)).collect();
+
+        let results = finder.howard_pred_exhaustive(&mut dist, |w| *w);
+        assert_eq!(results.len(), 2);
+
+        let mut weights: Vec<f64> = results.iter().map(|(_, w)| *w).collect();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(weights, vec![-5.0, -3.0]);
+    }
 }
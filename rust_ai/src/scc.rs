@@ -0,0 +1,105 @@
+//! Shared strongly-connected-components core
+//!
+//! Every graph representation in this crate (the dense [`crate::tiny_digraph::TinyDiGraph`],
+//! and the nested `HashMap<N, HashMap<N, E>>` representation used by
+//! [`crate::min_mean_cycle`] and [`crate::neg_cycle_q`]) needs strongly connected
+//! components, but none of them agree on how nodes are stored. This module factors
+//! out the index-based Tarjan core so each representation only has to supply its own
+//! `Vec<Vec<usize>>` adjacency list and map the resulting index components back to
+//! its own node type.
+
+/// Computes the strongly connected components of the graph described by
+/// `adj`, where `adj[u]` lists the indices of `u`'s out-neighbors.
+///
+/// Uses an iterative (non-recursive, so no call-stack depth limit on large
+/// graphs) version of Tarjan's algorithm: a DFS index counter and a
+/// `lowlink` per node, an explicit stack with an on-stack flag, and a node
+/// rooting an SCC whenever `lowlink == index`, at which point the stack is
+/// popped down to it.
+///
+/// # Returns
+///
+/// Each strongly connected component as a list of its member node indices.
+pub(crate) fn tarjan_scc(adj: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = adj.len();
+    let mut index: Vec<Option<usize>> = vec![None; n];
+    let mut lowlink: Vec<usize> = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut counter = 0;
+    let mut sccs: Vec<Vec<usize>> = Vec::new();
+
+    for start in 0..n {
+        if index[start].is_some() {
+            continue;
+        }
+
+        let mut frames: Vec<(usize, usize)> = vec![(start, 0)];
+        index[start] = Some(counter);
+        lowlink[start] = counter;
+        counter += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        while let Some(&mut (v, ref mut pos)) = frames.last_mut() {
+            if *pos < adj[v].len() {
+                let w = adj[v][*pos];
+                *pos += 1;
+
+                if index[w].is_none() {
+                    index[w] = Some(counter);
+                    lowlink[w] = counter;
+                    counter += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    frames.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w].expect("visited node has an index"));
+                }
+            } else {
+                frames.pop();
+
+                if lowlink[v] == index[v].expect("visited node has an index") {
+                    let mut component = Vec::new();
+                    loop {
+                        let w = stack.pop().expect("component root is on the stack");
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    sccs.push(component);
+                }
+
+                if let Some(&(parent, _)) = frames.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+            }
+        }
+    }
+
+    sccs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_cycle_is_one_component() {
+        let adj = vec![vec![1], vec![2], vec![0]];
+        let sccs = tarjan_scc(&adj);
+        assert_eq!(sccs.len(), 1);
+        let mut members = sccs[0].clone();
+        members.sort();
+        assert_eq!(members, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_disjoint_nodes_are_singleton_components() {
+        let adj = vec![vec![], vec![]];
+        let sccs = tarjan_scc(&adj);
+        assert_eq!(sccs.len(), 2);
+    }
+}
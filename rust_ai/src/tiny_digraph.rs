@@ -7,7 +7,7 @@
 //! efficiency are important.
 
 use indexmap::IndexMap;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 /// A lightweight directed graph implementation optimized for performance and memory efficiency.
@@ -145,7 +145,7 @@ where
     /// An iterator yielding tuples of (neighbor_node, edge_data).
     pub fn neighbors(&self, node: &N) -> impl Iterator<Item = (&N, &E)> {
         let idx = self.node_to_index.get(node).expect("Node not found");
-        self.adj[*idx].iter().map(|(v, edge)| (v, edge))
+        self.adj[*idx].iter()
     }
 
     /// Returns an iterator over the predecessors of a node.
@@ -159,7 +159,7 @@ where
     /// An iterator yielding tuples of (predecessor_node, edge_data).
     pub fn predecessors(&self, node: &N) -> impl Iterator<Item = (&N, &E)> {
         let idx = self.node_to_index.get(node).expect("Node not found");
-        self.pred[*idx].iter().map(|(u, edge)| (u, edge))
+        self.pred[*idx].iter()
     }
 
     /// Gets a mutable reference to node attributes.
@@ -189,6 +189,147 @@ where
         let idx = self.node_to_index.get(node).expect("Node not found");
         &self.nodes[*idx]
     }
+
+    /// Computes a vertex order via the Eades-Lin-Smyth heuristic.
+    ///
+    /// Repeatedly removes sinks (prepending them to the tail of the order)
+    /// and sources (appending them to the head), and when neither remains,
+    /// appends whichever vertex maximizes `out-degree - in-degree` among
+    /// those still present. The resulting order minimizes (heuristically)
+    /// the number of edges that point "backward" in it.
+    ///
+    /// # Returns
+    ///
+    /// The vertex order `s1` followed by `s2`.
+    pub fn linear_arrangement(&self) -> Vec<N> {
+        let mut remaining: HashSet<N> = self.nodes().cloned().collect();
+        let mut s1: Vec<N> = Vec::new();
+        let mut s2: Vec<N> = Vec::new();
+
+        let out_degree = |v: &N, remaining: &HashSet<N>| {
+            self.neighbors(v)
+                .filter(|(w, _)| remaining.contains(*w))
+                .count()
+        };
+        let in_degree = |v: &N, remaining: &HashSet<N>| {
+            self.predecessors(v)
+                .filter(|(w, _)| remaining.contains(*w))
+                .count()
+        };
+
+        while !remaining.is_empty() {
+            while let Some(sink) = remaining
+                .iter()
+                .find(|v| out_degree(v, &remaining) == 0)
+                .cloned()
+            {
+                remaining.remove(&sink);
+                s2.insert(0, sink);
+            }
+
+            while let Some(source) = remaining
+                .iter()
+                .find(|v| in_degree(v, &remaining) == 0)
+                .cloned()
+            {
+                remaining.remove(&source);
+                s1.push(source);
+            }
+
+            if let Some(best) = remaining
+                .iter()
+                .max_by_key(|v| out_degree(v, &remaining) as isize - in_degree(v, &remaining) as isize)
+                .cloned()
+            {
+                remaining.remove(&best);
+                s1.push(best);
+            }
+        }
+
+        s1.extend(s2);
+        s1
+    }
+
+    /// Computes a small feedback arc set whose removal makes the graph
+    /// acyclic, using the Eades-Lin-Smyth heuristic order from
+    /// [`TinyDiGraph::linear_arrangement`].
+    ///
+    /// Every edge pointing "backward" in that order (from a later vertex to
+    /// an earlier one) is returned as a feedback arc.
+    ///
+    /// # Returns
+    ///
+    /// The list of edges (as `(source, target)` pairs) to remove.
+    pub fn greedy_feedback_arc_set(&self) -> Vec<(N, N)> {
+        let order = self.linear_arrangement();
+        let position: HashMap<N, usize> = order
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+
+        self.edges()
+            .filter(|(u, v, _)| position[*u] > position[*v])
+            .map(|(u, v, _)| (u.clone(), v.clone()))
+            .collect()
+    }
+
+    /// Computes the strongly connected components via Tarjan's algorithm.
+    ///
+    /// Uses a single DFS (run with an explicit stack to avoid recursion
+    /// limits on large graphs) that tracks each node's discovery index and
+    /// lowlink, plus an on-stack flag; whenever a node's lowlink equals its
+    /// own index, it roots a component that is popped off the stack.
+    ///
+    /// # Returns
+    ///
+    /// The strongly connected components, each as a list of nodes.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let adj_idx: Vec<Vec<usize>> = self
+            .adj
+            .iter()
+            .map(|edges| edges.keys().map(|k| self.node_to_index[k]).collect())
+            .collect();
+
+        crate::scc::tarjan_scc(&adj_idx)
+            .into_iter()
+            .map(|component| component.into_iter().map(|i| self.index_to_node[i].clone()).collect())
+            .collect()
+    }
+
+    /// Collapses each strongly connected component into a single super-node,
+    /// producing the condensation DAG.
+    ///
+    /// Super-nodes are indexed by their position in the list returned by
+    /// [`TinyDiGraph::strongly_connected_components`]. An edge is kept
+    /// between two super-nodes whenever an edge exists between any pair of
+    /// their members; parallel super-edges collapse to the last one seen.
+    ///
+    /// # Returns
+    ///
+    /// The condensation graph, which is always acyclic.
+    pub fn condensation(&self) -> TinyDiGraph<usize, E> {
+        let sccs = self.strongly_connected_components();
+        let mut node_to_scc: HashMap<N, usize> = HashMap::new();
+        for (scc_id, component) in sccs.iter().enumerate() {
+            for node in component {
+                node_to_scc.insert(node.clone(), scc_id);
+            }
+        }
+
+        let mut condensed = TinyDiGraph::new();
+        condensed.init_nodes(0..sccs.len());
+
+        for (u, v, edge) in self.edges() {
+            let su = node_to_scc[u];
+            let sv = node_to_scc[v];
+            if su != sv {
+                condensed.add_edge(&su, &sv, edge.clone());
+            }
+        }
+
+        condensed
+    }
 }
 
 impl<N, E> Default for TinyDiGraph<N, E>
@@ -252,4 +393,83 @@ mod tests {
         assert!(predecessors.contains(&(&2, &"edge20")));
         assert!(predecessors.contains(&(&3, &"edge30")));
     }
+
+    #[test]
+    fn test_linear_arrangement_is_a_permutation() {
+        let mut gr: TinyDiGraph<i32, &str> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2, 3]);
+        gr.add_edge(&0, &1, "e01");
+        gr.add_edge(&1, &2, "e12");
+        gr.add_edge(&2, &3, "e23");
+        gr.add_edge(&3, &0, "e30");
+
+        let order = gr.linear_arrangement();
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_greedy_feedback_arc_set_breaks_all_cycles() {
+        let mut gr: TinyDiGraph<i32, &str> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2]);
+        gr.add_edge(&0, &1, "e01");
+        gr.add_edge(&1, &2, "e12");
+        gr.add_edge(&2, &0, "e20");
+
+        let fas = gr.greedy_feedback_arc_set();
+        // A single 3-cycle needs exactly one arc removed to become acyclic.
+        assert_eq!(fas.len(), 1);
+    }
+
+    #[test]
+    fn test_greedy_feedback_arc_set_on_dag_is_empty() {
+        let mut gr: TinyDiGraph<i32, &str> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2]);
+        gr.add_edge(&0, &1, "e01");
+        gr.add_edge(&1, &2, "e12");
+        gr.add_edge(&0, &2, "e02");
+
+        let fas = gr.greedy_feedback_arc_set();
+        assert!(fas.is_empty());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // Two triangles (0,1,2) and (3,4,5) joined by a one-way bridge.
+        let mut gr: TinyDiGraph<i32, &str> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2, 3, 4, 5]);
+        gr.add_edge(&0, &1, "e");
+        gr.add_edge(&1, &2, "e");
+        gr.add_edge(&2, &0, "e");
+        gr.add_edge(&3, &4, "e");
+        gr.add_edge(&4, &5, "e");
+        gr.add_edge(&5, &3, "e");
+        gr.add_edge(&2, &3, "e");
+
+        let mut sccs = gr.strongly_connected_components();
+        for component in &mut sccs {
+            component.sort();
+        }
+        sccs.sort();
+
+        assert_eq!(sccs, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_condensation_is_acyclic() {
+        let mut gr: TinyDiGraph<i32, &str> = TinyDiGraph::new();
+        gr.init_nodes(vec![0, 1, 2, 3, 4, 5]);
+        gr.add_edge(&0, &1, "e");
+        gr.add_edge(&1, &2, "e");
+        gr.add_edge(&2, &0, "e");
+        gr.add_edge(&3, &4, "e");
+        gr.add_edge(&4, &5, "e");
+        gr.add_edge(&5, &3, "e");
+        gr.add_edge(&2, &3, "e");
+
+        let condensed = gr.condensation();
+        assert_eq!(condensed.number_of_nodes(), 2);
+        assert_eq!(condensed.number_of_edges(), 1);
+    }
 }